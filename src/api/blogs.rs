@@ -1,7 +1,11 @@
 use leptos::{prelude::ServerFnError, server};
 
-use crate::models::blog::BlogPost;
+use crate::models::archive::{ArchivePeriod, ArchivePosts, ArchiveYear};
+use crate::models::blog::{BlogLookup, BlogPost, TagCount, TagPosts, Visibility};
+use crate::models::blog_index::{BlogIndexPage, BlogIndexQuery};
+use crate::models::series::{Series, SeriesPosts};
 
+/// Every published post, newest first. Drafts, unlisted and archived posts are left out.
 #[server(GetBlogs, "/api")]
 pub async fn get_blogs_server() -> Result<Vec<BlogPost>, ServerFnError> {
     use crate::services::cosmos::blog_repository::get_blog_service;
@@ -11,26 +15,102 @@ pub async fn get_blogs_server() -> Result<Vec<BlogPost>, ServerFnError> {
     let blog_service = get_blog_service()
         .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
 
-    // Fetch blog posts with proper error handling
-    let cosmos_blogs = blog_service
-        .list_blog_posts() // Fetch up to 50 posts
+    let blog_posts = blog_service
+        .list_published_blog_posts()
         .await
         .map_err(|e| {
             logging::error!("Failed to fetch blogs from Cosmos DB: {:?}", e);
             ServerFnError::new(format!("Failed to get blogs: {e}"))
         })?;
 
-    logging::log!("Retrieved {} blogs from Cosmos DB", cosmos_blogs.len());
+    logging::log!("Retrieved {} blogs from Cosmos DB", blog_posts.len());
+    Ok(blog_posts)
+}
 
-    // Convert to BlogPost models
-    let blog_posts: Vec<BlogPost> = cosmos_blogs.into_iter().collect();
+/// One page of the blog index with the filters and order in `query` applied, plus the
+/// tags and years the filter controls offer. A page past the end comes back empty.
+#[server(GetBlogIndex, "/api")]
+pub async fn get_blog_index_server(query: BlogIndexQuery) -> Result<BlogIndexPage, ServerFnError> {
+    use crate::models::blog_index::POSTS_PER_PAGE;
+    use crate::services::config::get_config;
+    use crate::services::cosmos::blog_repository::{get_blog_service, PostFilter};
+    use leptos::logging;
 
-    Ok(blog_posts)
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+    let fail = |e: color_eyre::Report| {
+        logging::error!("Failed to fetch blog index from Cosmos DB: {:?}", e);
+        ServerFnError::new(format!("Failed to get blogs: {e}"))
+    };
+
+    let (tags, years) = futures::try_join!(
+        blog_service.list_tag_counts(),
+        blog_service.list_published_years()
+    )
+    .map_err(fail)?;
+
+    let tag_name = query.tag.as_ref().and_then(|slug| {
+        tags.iter()
+            .find(|count| &count.slug == slug)
+            .map(|count| count.tag.clone())
+    });
+    let filter = PostFilter {
+        tags: match &query.tag {
+            Some(slug) => Some(blog_service.find_tag_spellings(slug).await.map_err(fail)?),
+            None => None,
+        },
+        year: query.year,
+        month: None,
+    };
+
+    // An unknown tag matches nothing, and an empty tag list would not filter at all
+    let total = if filter.tags.as_ref().is_some_and(Vec::is_empty) {
+        0
+    } else {
+        blog_service
+            .count_published_posts(&filter)
+            .await
+            .map_err(fail)?
+    };
+    let page_count = u32::try_from(total.div_ceil(POSTS_PER_PAGE as usize)).unwrap_or(u32::MAX);
+
+    let posts = if (1..=page_count).contains(&query.page) {
+        blog_service
+            .list_published_posts_page(
+                &filter,
+                query.sort,
+                query.page.saturating_sub(1).saturating_mul(POSTS_PER_PAGE),
+                POSTS_PER_PAGE,
+            )
+            .await
+            .map_err(fail)?
+    } else {
+        Vec::new()
+    };
+
+    logging::log!(
+        "Retrieved page {} of {} ({} posts) from Cosmos DB",
+        query.page,
+        page_count,
+        total
+    );
+    Ok(BlogIndexPage {
+        query,
+        posts,
+        total,
+        page_count,
+        tag_name,
+        tags,
+        years,
+        site_url: get_config().site.base_url.clone(),
+    })
 }
 
+/// The post with `id`, if readers may open it.
 #[server(GetBlog, "/api")]
 pub async fn get_blog_server(id: String) -> Result<BlogPost, ServerFnError> {
     use crate::services::cosmos::blog_repository::get_blog_service;
+    use chrono::Utc;
     use leptos::logging;
 
     // Get the blog service
@@ -43,12 +123,95 @@ pub async fn get_blog_server(id: String) -> Result<BlogPost, ServerFnError> {
         ServerFnError::new(format!("Failed to get blog post: {e}"))
     })?;
 
-    logging::log!("Retrieved blog post with ID: {}", id);
+    // Drafts and archived posts look exactly like missing ones
+    if !cosmos_blog.is_reachable_at(Utc::now()) {
+        return Err(ServerFnError::new(format!("Blog post '{id}' not found")));
+    }
 
-    // Convert to BlogPost model
+    logging::log!("Retrieved blog post with ID: {}", id);
     Ok(cosmos_blog)
 }
 
+/// Resolves a `/blog/:slug` URL. Old UUID links and renamed slugs resolve to
+/// [`BlogLookup::Moved`] so the caller can redirect to the canonical slug, archived
+/// posts to [`BlogLookup::Gone`]. Drafts are reported as not found.
+#[server(GetBlogBySlug, "/api")]
+pub async fn get_blog_by_slug_server(slug: String) -> Result<BlogLookup, ServerFnError> {
+    use crate::models::blog::BlogArticle;
+    use crate::services::config::get_config;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::cosmos::series_repository::get_series_service;
+    use chrono::Utc;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let lookup_error = |e: color_eyre::Report| {
+        logging::error!("Failed to look up blog post '{}': {:?}", slug, e);
+        ServerFnError::new(format!("Failed to get blog post: {e}"))
+    };
+    let not_found = || ServerFnError::new(format!("Blog post '{slug}' not found"));
+    let now = Utc::now();
+
+    let post = if let Some(post) = blog_service
+        .get_blog_post_by_slug(&slug)
+        .await
+        .map_err(lookup_error)?
+    {
+        post
+    } else {
+        // Links from before slugs existed use the post id
+        let legacy = if uuid::Uuid::parse_str(&slug).is_ok() {
+            blog_service.get_blog_post(&slug).await.ok()
+        } else {
+            None
+        };
+        let renamed = match legacy {
+            Some(post) => Some(post),
+            None => blog_service
+                .find_blog_post_by_previous_slug(&slug)
+                .await
+                .map_err(lookup_error)?,
+        };
+
+        match renamed {
+            Some(post) if post.visibility_at(now) != Visibility::Draft => {
+                if post.slug.is_empty() {
+                    post
+                } else {
+                    return Ok(BlogLookup::Moved { slug: post.slug });
+                }
+            }
+            _ => return Err(not_found()),
+        }
+    };
+
+    match post.visibility_at(now) {
+        Visibility::Public | Visibility::Unlisted => {}
+        Visibility::Archived => return Ok(BlogLookup::Gone { title: post.title }),
+        Visibility::Draft => return Err(not_found()),
+    }
+
+    let mut article = BlogArticle::render(post, get_config().site.base_url.clone());
+    // A broken series must not take the post down with it
+    article.series = match get_series_service() {
+        Ok(series_service) => series_service
+            .navigation_for(blog_service, &article.post)
+            .await
+            .unwrap_or_else(|e| {
+                logging::error!("Failed to load series for '{}': {:?}", slug, e);
+                None
+            }),
+        Err(e) => {
+            logging::error!("Series service unavailable: {:?}", e);
+            None
+        }
+    };
+
+    Ok(BlogLookup::Found(Box::new(article)))
+}
+
 #[server(GetLatestBlogs, "/api")]
 pub async fn get_latest_blogs_server(limit: i32) -> Result<Vec<BlogPost>, ServerFnError> {
     use crate::services::cosmos::blog_repository::get_blog_service;
@@ -56,7 +219,7 @@ pub async fn get_latest_blogs_server(limit: i32) -> Result<Vec<BlogPost>, Server
 
     // Get the blog service
     let blog_service = get_blog_service()
-        .map_err(|e| ServerFnError::new(format!("Failed to get blog service: {}", e)))?;
+        .map_err(|e| ServerFnError::new(format!("Failed to get blog service: {e}")))?;
 
     // Fetch latest blogs with improved error handling
     let blogs = blog_service
@@ -64,9 +227,430 @@ pub async fn get_latest_blogs_server(limit: i32) -> Result<Vec<BlogPost>, Server
         .await
         .map_err(|e| {
             logging::error!("Failed to fetch latest blogs from Cosmos DB: {:?}", e);
-            ServerFnError::new(format!("Failed to get latest blogs: {}", e))
+            ServerFnError::new(format!("Failed to get latest blogs: {e}"))
         })?;
 
     logging::log!("Retrieved {} latest blogs from Cosmos DB", blogs.len());
     Ok(blogs)
 }
+
+#[server(GetTags, "/api")]
+pub async fn get_tags_server() -> Result<Vec<TagCount>, ServerFnError> {
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let tags = blog_service.list_tag_counts().await.map_err(|e| {
+        logging::error!("Failed to fetch tags from Cosmos DB: {:?}", e);
+        ServerFnError::new(format!("Failed to get tags: {e}"))
+    })?;
+
+    logging::log!("Retrieved {} tags from Cosmos DB", tags.len());
+    Ok(tags)
+}
+
+#[server(GetTagPosts, "/api")]
+pub async fn get_tag_posts_server(tag_slug: String) -> Result<TagPosts, ServerFnError> {
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let spellings = blog_service
+        .find_tag_spellings(&tag_slug)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to resolve tag '{}': {:?}", tag_slug, e);
+            ServerFnError::new(format!("Failed to get tag: {e}"))
+        })?;
+
+    let Some(tag) = spellings.first().cloned() else {
+        return Err(ServerFnError::new(format!("Tag '{tag_slug}' not found")));
+    };
+
+    let posts = blog_service
+        .list_published_posts_by_tags(&spellings)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to fetch posts for tag '{}': {:?}", tag_slug, e);
+            ServerFnError::new(format!("Failed to get posts for tag: {e}"))
+        })?;
+
+    logging::log!("Retrieved {} posts for tag '{}'", posts.len(), tag);
+    Ok(TagPosts { tag, posts })
+}
+
+/// Published post counts per year and month, newest year first.
+#[server(GetArchive, "/api")]
+pub async fn get_archive_server() -> Result<Vec<ArchiveYear>, ServerFnError> {
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let months = blog_service.list_archive_months().await.map_err(|e| {
+        logging::error!("Failed to fetch archive from Cosmos DB: {:?}", e);
+        ServerFnError::new(format!("Failed to get archive: {e}"))
+    })?;
+
+    logging::log!("Retrieved {} archive months from Cosmos DB", months.len());
+    Ok(ArchiveYear::group(months))
+}
+
+/// Published posts of an archive year or month, oldest first.
+#[server(GetArchivePosts, "/api")]
+pub async fn get_archive_posts_server(
+    period: ArchivePeriod,
+) -> Result<ArchivePosts, ServerFnError> {
+    use crate::models::blog_index::PostSort;
+    use crate::services::cosmos::blog_repository::{get_blog_service, PostFilter};
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let filter = PostFilter {
+        tags: None,
+        year: Some(period.year),
+        month: period.month,
+    };
+    let posts = blog_service
+        .list_filtered_posts(&filter, PostSort::Oldest)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to fetch posts for {}: {:?}", period.label(), e);
+            ServerFnError::new(format!("Failed to get archive: {e}"))
+        })?;
+
+    if posts.is_empty() {
+        return Err(ServerFnError::new(format!(
+            "No posts published in {}",
+            period.label()
+        )));
+    }
+
+    logging::log!("Retrieved {} posts for {}", posts.len(), period.label());
+    Ok(ArchivePosts { period, posts })
+}
+
+#[server(GetSeriesPosts, "/api")]
+pub async fn get_series_posts_server(series_slug: String) -> Result<SeriesPosts, ServerFnError> {
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::cosmos::series_repository::get_series_service;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+    let series_service = get_series_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get series service: {e}")))?;
+
+    let series_posts = series_service
+        .series_posts(blog_service, &series_slug)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to fetch series '{}': {:?}", series_slug, e);
+            ServerFnError::new(format!("Failed to get series: {e}"))
+        })?;
+
+    let Some(series_posts) = series_posts else {
+        return Err(ServerFnError::new(format!(
+            "Series '{series_slug}' not found"
+        )));
+    };
+
+    logging::log!(
+        "Retrieved {} posts for series '{}'",
+        series_posts.posts.len(),
+        series_posts.series.title
+    );
+    Ok(series_posts)
+}
+
+/// Published posts related to the post with `post_id`, for the bottom of the post page.
+#[server(GetRelatedPosts, "/api")]
+pub async fn get_related_posts_server(post_id: String) -> Result<Vec<BlogPost>, ServerFnError> {
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::related_posts::find_related_posts;
+    use leptos::logging;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    find_related_posts(blog_service, &post_id)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to find posts related to '{}': {:?}", post_id, e);
+            ServerFnError::new(format!("Failed to get related posts: {e}"))
+        })
+}
+
+/// Every post regardless of visibility, newest first, optionally only those with
+/// `visibility`.
+///
+/// Admin only.
+#[server(GetAdminBlogs, "/api")]
+pub async fn get_admin_blogs_server(
+    visibility: Option<Visibility>,
+) -> Result<Vec<BlogPost>, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    blog_service
+        .list_all_blog_posts(visibility)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to fetch posts for admin: {:?}", e);
+            ServerFnError::new(format!("Failed to get blogs: {e}"))
+        })
+}
+
+/// Changes who can see the post with `post_id`.
+///
+/// Admin only.
+#[server(SetVisibility, "/api")]
+pub async fn set_visibility_server(
+    post_id: String,
+    visibility: Visibility,
+) -> Result<BlogPost, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let post = blog_service
+        .set_visibility(&post_id, visibility)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to set visibility of '{}': {:?}", post_id, e);
+            ServerFnError::new(format!("Failed to set visibility: {e}"))
+        })?;
+
+    logging::log!("Post '{}' is now {}", post.title, visibility.as_str());
+    let id = post.id.clone();
+    events::publish(if visibility.is_listed() {
+        ContentEvent::PostPublished { id }
+    } else {
+        ContentEvent::PostUnpublished { id }
+    });
+    Ok(post)
+}
+
+/// Shows or hides the table of contents on the page of the post with `post_id`.
+///
+/// Admin only.
+#[server(SetShowToc, "/api")]
+pub async fn set_show_toc_server(
+    post_id: String,
+    show_toc: bool,
+) -> Result<BlogPost, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let post = blog_service
+        .set_show_toc(&post_id, show_toc)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to set table of contents of '{}': {:?}", post_id, e);
+            ServerFnError::new(format!("Failed to set table of contents: {e}"))
+        })?;
+
+    logging::log!(
+        "Post '{}' {} its table of contents",
+        post.title,
+        if show_toc { "shows" } else { "hides" }
+    );
+    events::publish(ContentEvent::PostUpdated {
+        id: post.id.clone(),
+    });
+    Ok(post)
+}
+
+/// Stores `post` as a new post with a slug derived from its title.
+///
+/// Admin only.
+#[server(CreateBlog, "/api")]
+pub async fn create_blog_server(post: BlogPost) -> Result<BlogPost, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::events::{self, ContentEvent};
+    use chrono::Utc;
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let post = blog_service.create_blog(post).await.map_err(|e| {
+        logging::error!("Failed to create blog post: {:?}", e);
+        ServerFnError::new(format!("Failed to create blog post: {e}"))
+    })?;
+
+    logging::log!("Created post '{}'", post.title);
+    events::publish(ContentEvent::post_changed(
+        post.id.clone(),
+        false,
+        post.is_listed_at(Utc::now()),
+    ));
+    Ok(post)
+}
+
+/// Replaces the stored post with the same id as `post`. A new title moves the post to
+/// a new slug and keeps the old one as a redirect.
+///
+/// Admin only.
+#[server(UpdateBlog, "/api")]
+pub async fn update_blog_server(post: BlogPost) -> Result<BlogPost, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::events::{self, ContentEvent};
+    use chrono::Utc;
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    let now = Utc::now();
+    let was_listed = blog_service
+        .get_blog_post(&post.id)
+        .await
+        .is_ok_and(|existing| existing.is_listed_at(now));
+    let post = blog_service.update_blog(post).await.map_err(|e| {
+        logging::error!("Failed to update blog post: {:?}", e);
+        ServerFnError::new(format!("Failed to update blog post: {e}"))
+    })?;
+
+    logging::log!("Updated post '{}'", post.title);
+    events::publish(ContentEvent::post_changed(
+        post.id.clone(),
+        was_listed,
+        post.is_listed_at(now),
+    ));
+    Ok(post)
+}
+
+/// Deletes the post with `post_id`. Its URLs answer `404` afterwards.
+///
+/// Admin only.
+#[server(DeleteBlog, "/api")]
+pub async fn delete_blog_server(post_id: String) -> Result<(), ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+
+    blog_service.delete_blog(&post_id).await.map_err(|e| {
+        logging::error!("Failed to delete blog post '{}': {:?}", post_id, e);
+        ServerFnError::new(format!("Failed to delete blog post: {e}"))
+    })?;
+
+    logging::log!("Deleted post '{}'", post_id);
+    events::publish(ContentEvent::PostDeleted { id: post_id });
+    Ok(())
+}
+
+/// Creates a series, or updates the one with `series_id`, with `post_ids` as its parts
+/// in reading order. Posts dropped from the series are detached from it.
+///
+/// Admin only.
+#[server(SaveSeries, "/api")]
+pub async fn save_series_server(
+    series_id: Option<String>,
+    title: String,
+    description: String,
+    post_ids: Vec<String>,
+) -> Result<Series, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::blog_repository::{get_blog_service, PARTITION_KEY};
+    use crate::services::cosmos::series_repository::get_series_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let title = title.trim().to_string();
+    if title.is_empty() {
+        return Err(ServerFnError::new("A series needs a title"));
+    }
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+    let series_service = get_series_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get series service: {e}")))?;
+
+    let mut series = match series_id {
+        Some(id) => series_service
+            .get_series(&id)
+            .await
+            .map_err(|e| {
+                logging::error!("Failed to load series '{}': {:?}", id, e);
+                ServerFnError::new(format!("Failed to get series: {e}"))
+            })?
+            .ok_or_else(|| ServerFnError::new(format!("Series '{id}' not found")))?,
+        None => Series::new(title.clone(), String::new(), PARTITION_KEY.to_string()),
+    };
+
+    // Check every part before anything is written, so a typo can't leave half a series
+    let mut parts: Vec<String> = Vec::with_capacity(post_ids.len());
+    for post_id in post_ids {
+        if parts.contains(&post_id) {
+            continue;
+        }
+        blog_service.get_blog_post(&post_id).await.map_err(|e| {
+            logging::error!("Unknown post '{}' for series '{}': {:?}", post_id, title, e);
+            ServerFnError::new(format!("Blog post '{post_id}' not found"))
+        })?;
+        parts.push(post_id);
+    }
+
+    series.title = title;
+    series.description = description.trim().to_string();
+    series.post_ids = parts;
+
+    let series = series_service
+        .save_series(series, blog_service)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to save series: {:?}", e);
+            ServerFnError::new(format!("Failed to save series: {e}"))
+        })?;
+
+    logging::log!(
+        "Saved series '{}' with {} parts",
+        series.title,
+        series.post_ids.len()
+    );
+    events::publish(ContentEvent::SeriesUpdated {
+        id: series.id.clone(),
+    });
+    Ok(series)
+}
@@ -21,3 +21,55 @@ pub async fn get_books_server() -> Result<Vec<Book>, ServerFnError> {
 
     Ok(books)
 }
+
+/// Adds `book`, or replaces the book with the same id.
+///
+/// Admin only.
+#[server(SaveBook, "/api")]
+pub async fn save_book_server(book: Book) -> Result<Book, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::book_repository::get_book_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let book_repo = get_book_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get book repository: {e}")))?;
+
+    let book = book_repo.save_book(book).await.map_err(|e| {
+        logging::error!("Failed to save book: {:?}", e);
+        ServerFnError::new(format!("Failed to save book: {e}"))
+    })?;
+
+    logging::log!("Saved book '{}'", book.title);
+    events::publish(ContentEvent::BookUpdated {
+        id: book.id.clone(),
+    });
+    Ok(book)
+}
+
+/// Deletes the book with `book_id`.
+///
+/// Admin only.
+#[server(DeleteBook, "/api")]
+pub async fn delete_book_server(book_id: String) -> Result<(), ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::book_repository::get_book_service;
+    use crate::services::events::{self, ContentEvent};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let book_repo = get_book_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get book repository: {e}")))?;
+
+    book_repo.delete_book(&book_id).await.map_err(|e| {
+        logging::error!("Failed to delete book '{}': {:?}", book_id, e);
+        ServerFnError::new(format!("Failed to delete book: {e}"))
+    })?;
+
+    logging::log!("Deleted book '{}'", book_id);
+    events::publish(ContentEvent::BookDeleted { id: book_id });
+    Ok(())
+}
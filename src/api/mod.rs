@@ -1,2 +1,4 @@
 pub mod blogs;
 pub mod books;
+pub mod previews;
+pub mod search;
@@ -0,0 +1,156 @@
+use leptos::{prelude::ServerFnError, server};
+
+use crate::models::preview::{CreatedPreviewLink, PreviewArticle, PreviewLinkSummary};
+
+/// Resolves a `/blog/preview/:token` URL to the revision it was created for.
+#[server(GetPreview, "/api")]
+pub async fn get_preview_server(token: String) -> Result<PreviewArticle, ServerFnError> {
+    use crate::models::blog::BlogArticle;
+    use crate::services::config::get_config;
+    use crate::services::cosmos::preview_repository::get_preview_service;
+    use crate::services::preview::verify_preview_token;
+    use chrono::Utc;
+    use leptos::logging;
+
+    let now = Utc::now();
+    let link_id = verify_preview_token(&token, now, &get_config().admin.session_signing_keys)
+        .map_err(|e| {
+            logging::warn!("Rejected preview token: {}", e);
+            ServerFnError::new(e.to_string())
+        })?;
+
+    let preview_service = get_preview_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get preview service: {e}")))?;
+    let link = preview_service.get_link(&link_id).await.map_err(|e| {
+        logging::error!("Failed to load preview link '{}': {:?}", link_id, e);
+        ServerFnError::new(format!("Failed to get preview: {e}"))
+    })?;
+
+    let Some(link) = link.filter(|link| link.is_active(now)) else {
+        return Err(ServerFnError::new(
+            "Preview link has been revoked or no longer exists",
+        ));
+    };
+
+    Ok(PreviewArticle {
+        expires_at: link.expires_at,
+        article: BlogArticle::render(link.post, get_config().site.base_url.clone()),
+    })
+}
+
+/// Creates a preview link for the current revision of the post with `post_id`.
+/// The link expires after `ttl_hours`, 72 hours by default and at most 30 days.
+///
+/// Admin only.
+#[server(CreatePreviewLink, "/api")]
+pub async fn create_preview_link_server(
+    post_id: String,
+    ttl_hours: Option<u32>,
+) -> Result<CreatedPreviewLink, ServerFnError> {
+    use crate::models::preview::{preview_path, PreviewLink};
+    use crate::services::admin::require_admin;
+    use crate::services::config::get_config;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::cosmos::preview_repository::get_preview_service;
+    use crate::services::preview::{sign_preview_token, DEFAULT_PREVIEW_TTL, MAX_PREVIEW_TTL};
+    use crate::utils::url::absolute_url;
+    use chrono::{Duration, Utc};
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+    let preview_service = get_preview_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get preview service: {e}")))?;
+
+    let post = blog_service.get_blog_post(&post_id).await.map_err(|e| {
+        logging::error!("Failed to load post '{}' for preview: {:?}", post_id, e);
+        ServerFnError::new(format!("Failed to get blog post: {e}"))
+    })?;
+
+    let ttl = ttl_hours
+        .map_or(DEFAULT_PREVIEW_TTL, |hours| {
+            Duration::hours(i64::from(hours))
+        })
+        .min(MAX_PREVIEW_TTL);
+    let link = PreviewLink::new(post, Utc::now() + ttl);
+    let token = sign_preview_token(
+        &link.id,
+        link.expires_at,
+        &get_config().admin.session_signing_keys,
+    )
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let link = preview_service.create_link(link).await.map_err(|e| {
+        logging::error!("Failed to store preview link for '{}': {:?}", post_id, e);
+        ServerFnError::new(format!("Failed to create preview link: {e}"))
+    })?;
+
+    logging::log!(
+        "Created preview link '{}' for '{}', expiring {}",
+        link.id,
+        link.post.title,
+        link.expires_at
+    );
+    Ok(CreatedPreviewLink {
+        url: absolute_url(&get_config().site.base_url, &preview_path(&token)),
+        link: PreviewLinkSummary {
+            id: link.id,
+            post_id: link.post_id,
+            post_title: link.post.title,
+            revision: link.post.updated_at,
+            created_at: link.created_at,
+            expires_at: link.expires_at,
+            revoked_at: link.revoked_at,
+        },
+    })
+}
+
+/// Every preview link created for the post with `post_id`, newest first.
+///
+/// Admin only.
+#[server(ListPreviewLinks, "/api")]
+pub async fn list_preview_links_server(
+    post_id: String,
+) -> Result<Vec<PreviewLinkSummary>, ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::preview_repository::get_preview_service;
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let preview_service = get_preview_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get preview service: {e}")))?;
+    preview_service.list_links(&post_id).await.map_err(|e| {
+        logging::error!("Failed to list preview links for '{}': {:?}", post_id, e);
+        ServerFnError::new(format!("Failed to list preview links: {e}"))
+    })
+}
+
+/// Revokes the preview link with `link_id`; its URL stops working immediately.
+///
+/// Admin only.
+#[server(RevokePreviewLink, "/api")]
+pub async fn revoke_preview_link_server(link_id: String) -> Result<(), ServerFnError> {
+    use crate::services::admin::require_admin;
+    use crate::services::cosmos::preview_repository::get_preview_service;
+    use leptos::logging;
+
+    require_admin().await?;
+
+    let preview_service = get_preview_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get preview service: {e}")))?;
+    let revoked = preview_service.revoke_link(&link_id).await.map_err(|e| {
+        logging::error!("Failed to revoke preview link '{}': {:?}", link_id, e);
+        ServerFnError::new(format!("Failed to revoke preview link: {e}"))
+    })?;
+
+    if !revoked {
+        return Err(ServerFnError::new(format!(
+            "Preview link '{link_id}' not found"
+        )));
+    }
+    logging::log!("Revoked preview link '{}'", link_id);
+    Ok(())
+}
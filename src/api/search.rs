@@ -0,0 +1,37 @@
+use leptos::{prelude::ServerFnError, server};
+
+use crate::models::search::SearchResults;
+
+/// Published posts and books matching `query`, best match first. Queries longer than
+/// [`MAX_QUERY_CHARS`](crate::models::search::MAX_QUERY_CHARS) are cut off.
+#[server(Search, "/api")]
+pub async fn search_server(query: String) -> Result<SearchResults, ServerFnError> {
+    use crate::models::search::MAX_QUERY_CHARS;
+    use crate::services::cosmos::blog_repository::get_blog_service;
+    use crate::services::cosmos::book_repository::get_book_service;
+    use crate::services::search::search;
+    use leptos::logging;
+
+    let query: String = query.trim().chars().take(MAX_QUERY_CHARS).collect();
+    if query.is_empty() {
+        return Ok(SearchResults {
+            query,
+            hits: Vec::new(),
+        });
+    }
+
+    let blog_service = get_blog_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get Cosmos service: {e}")))?;
+    let book_service = get_book_service()
+        .map_err(|e| ServerFnError::new(format!("Failed to get book repository: {e}")))?;
+
+    let hits = search(blog_service, book_service, &query)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to search for '{}': {:?}", query, e);
+            ServerFnError::new(format!("Failed to search: {e}"))
+        })?;
+
+    logging::log!("Found {} results for '{}'", hits.len(), query);
+    Ok(SearchResults { query, hits })
+}
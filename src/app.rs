@@ -8,12 +8,17 @@ use leptos_router::{
 };
 
 use crate::components::blog_detail::BlogDetail;
+use crate::components::blog_preview::BlogPreview;
 use crate::components::footer::Footer;
 use crate::components::navigation::Navigation;
 use crate::pages::about::AboutPage;
+use crate::pages::archive::{ArchivePage, ArchivePeriodPage};
 use crate::pages::blog::BlogPage;
 use crate::pages::book::BooksPage;
 use crate::pages::home::Home;
+use crate::pages::search::SearchPage;
+use crate::pages::series::SeriesPage;
+use crate::pages::tags::{TagPage, TagsPage};
 
 #[must_use]
 pub fn shell(options: LeptosOptions) -> impl IntoView {
@@ -59,26 +64,29 @@ pub fn App() -> impl IntoView {
         <Meta name="author" content="Mikko Leppänen" />
 
         <Link rel="icon" type_="image/png" sizes="64x64" href="/images/dev-logo-64x64.png" />
+        <Link
+            rel="alternate"
+            type_="application/rss+xml"
+            title="Mike's Dev Blog (RSS)"
+            href="/feed.xml"
+        />
+        <Link
+            rel="alternate"
+            type_="application/atom+xml"
+            title="Mike's Dev Blog (Atom)"
+            href="/atom.xml"
+        />
+        <Link
+            rel="alternate"
+            type_="application/feed+json"
+            title="Mike's Dev Blog (JSON Feed)"
+            href="/feed.json"
+        />
         // <Link rel="stylesheet" href="/style/main.css" />
         <Link rel="stylesheet" href="/assets/main.css" />
+        <Link rel="stylesheet" href="/assets/syntax.css" />
         <Stylesheet href="https://cdn.jsdelivr.net/npm/tailwindcss@2.2.19/dist/tailwind.min.css" />
 
-        // Prism.js for code highlighting (optional)
-        <Stylesheet href="https://cdn.jsdelivr.net/npm/prismjs@1.29.0/themes/prism-tomorrow.min.css" />
-
-        // Prism.js Core
-        <Script src="https://cdnjs.cloudflare.com/ajax/libs/prism/1.29.0/components/prism-core.min.js" />
-
-        // Prism.js Language Support
-        <Script src="https://cdnjs.cloudflare.com/ajax/libs/prism/1.29.0/plugins/autoloader/prism-autoloader.min.js" />
-
-        // Prism.js Plugins for better experience
-        <Script src="https://cdnjs.cloudflare.com/ajax/libs/prism/1.29.0/plugins/copy-to-clipboard/prism-copy-to-clipboard.min.js" />
-        <Link
-            rel="stylesheet"
-            href="https://cdnjs.cloudflare.com/ajax/libs/prism/1.29.0/plugins/copy-to-clipboard/prism-copy-to-clipboard.min.css"
-        />
-
         // Custom styles and JavaScript
         <Style>
             r#"
@@ -275,9 +283,17 @@ pub fn App() -> impl IntoView {
             "#
         </Style>
 
-        // Copy to clipboard functionality
+        // Copy to clipboard functionality. One delegated listener covers every code
+        // block, so rendered posts never need inline event handlers.
         <Script>
             r#"
+            document.addEventListener('click', function(event) {
+            const button = event.target.closest && event.target.closest('.copy-button');
+            if (button) {
+                copyCode(button);
+            }
+            });
+
             function copyCode(button) {
             const codeBlock = button.closest('.code-block-wrapper').querySelector('code');
             const text = codeBlock.textContent;
@@ -296,13 +312,6 @@ pub fn App() -> impl IntoView {
                 console.error('Failed to copy code to clipboard');
             });
             }
-            
-            // Ensure Prism highlighting runs after page loads
-            document.addEventListener('DOMContentLoaded', function() {
-            if (typeof Prism !== 'undefined') {
-                Prism.highlightAll();
-            }
-            });
             "#
         </Script>
 
@@ -310,8 +319,19 @@ pub fn App() -> impl IntoView {
     }
 }
 
+/// Routes of [`AppRoutes`] that don't depend on stored content, listed in the sitemap.
+pub const STATIC_ROUTES: &[&str] = &[
+    "/",
+    "/blog",
+    "/blog/tags",
+    "/blog/archive",
+    "/books",
+    "/about",
+];
+
 #[component]
 #[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
 pub fn AppRoutes() -> impl IntoView {
     view! {
         <Router>
@@ -352,12 +372,65 @@ pub fn AppRoutes() -> impl IntoView {
                             />
 
                             <Route
-                                path=path!("/blog/:id")
+                                path=path!("/blog/tags")
+                                view=|| {
+                                    view! { <TagsPage /> }
+                                }
+                            />
+                            <Route
+                                path=path!("/blog/tags/:tag")
+                                view=|| {
+                                    view! { <TagPage /> }
+                                }
+                            />
+
+                            <Route
+                                path=path!("/blog/archive")
+                                view=|| {
+                                    view! { <ArchivePage /> }
+                                }
+                            />
+                            <Route
+                                path=path!("/blog/archive/:year")
+                                view=|| {
+                                    view! { <ArchivePeriodPage /> }
+                                }
+                            />
+                            <Route
+                                path=path!("/blog/archive/:year/:month")
+                                view=|| {
+                                    view! { <ArchivePeriodPage /> }
+                                }
+                            />
+
+                            <Route
+                                path=path!("/blog/preview/:token")
+                                view=|| {
+                                    view! { <BlogPreview /> }
+                                }
+                            />
+
+                            <Route
+                                path=path!("/blog/:slug")
                                 view=|| {
                                     view! { <BlogDetail /> }
                                 }
                             />
 
+                            <Route
+                                path=path!("/series/:slug")
+                                view=|| {
+                                    view! { <SeriesPage /> }
+                                }
+                            />
+
+                            <Route
+                                path=StaticSegment("search")
+                                view=|| {
+                                    view! { <SearchPage /> }
+                                }
+                            />
+
                             <Route
                                 path=StaticSegment("books")
                                 view=|| {
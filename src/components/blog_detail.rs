@@ -1,61 +1,33 @@
-#![allow(clippy::cast_possible_truncation)]
-#![allow(clippy::cast_sign_loss)]
 #![allow(clippy::map_unwrap_or)]
-#![allow(clippy::cast_precision_loss)]
-#![allow(unused_unsafe)]
-use js_sys::wasm_bindgen;
+use leptos::prelude::*;
 use leptos::server::Resource;
-use leptos::{html, prelude::*};
-use leptos_meta::{Meta, Title};
 use leptos_router::hooks::use_params_map;
 
-use crate::api::blogs::get_blog_server;
+use crate::api::blogs::get_blog_by_slug_server;
+use crate::components::permanent_redirect::PermanentRedirect;
+use crate::components::post_metadata::PostMetadata;
+use crate::components::related_posts::RelatedPosts;
+use crate::components::series_parts::SeriesParts;
+use crate::components::table_of_contents::TableOfContents;
+use crate::components::tag_chips::TagChips;
+use crate::models::blog::{BlogArticle, BlogLookup};
 use crate::utils::date_formatter::format_date_readable;
 use leptos::suspense::Suspense;
-use leptos::web_sys;
 use leptos_router::components::A;
-use wasm_bindgen::prelude::*;
-
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = Prism)]
-    fn highlightAll();
-
-    #[wasm_bindgen(js_namespace = Prism)]
-    fn highlightAllUnder(element: web_sys::Element);
-}
 
 #[component]
 #[allow(clippy::too_many_lines)]
 #[allow(clippy::must_use_candidate)]
 pub fn BlogDetail() -> impl IntoView {
     let params = use_params_map();
-    let content_ref = NodeRef::<html::Div>::new();
-    let blog_id = move || params.with(|params| params.get("id").unwrap_or_default());
+    let blog_slug = move || params.with(|params| params.get("slug").unwrap_or_default());
 
-    // Resource to fetch the blog post
-    let blog_resource = Resource::new(blog_id, |id| async move {
-        if id.is_empty() {
-            return Err("Blog ID not found".to_string());
-        }
-        get_blog_server(id).await.map_err(|e| e.to_string())
-    });
-
-    Effect::new(move |_| {
-        if let Some(Ok(_)) = blog_resource.get() {
-            // Small delay to ensure DOM is updated
-            set_timeout(
-                move || {
-                    if let Some(element) = content_ref.get() {
-                        unsafe { highlightAllUnder(element.into()) };
-                    } else {
-                        // Fallback to highlighting all if element ref fails
-                        unsafe { highlightAll() };
-                    }
-                },
-                std::time::Duration::from_millis(100),
-            );
+    // Blocking so that redirects and 404s can set the response status during SSR
+    let blog_resource = Resource::new_blocking(blog_slug, |slug| async move {
+        if slug.is_empty() {
+            return Err("Blog post not found".to_string());
         }
+        get_blog_by_slug_server(slug).await.map_err(|e| e.to_string())
     });
 
     view! {
@@ -75,179 +47,52 @@ pub fn BlogDetail() -> impl IntoView {
             }
         }>
             {move || match blog_resource.get() {
-                Some(Ok(blog_post)) => {
+                Some(Ok(BlogLookup::Moved { slug })) => {
+                    view! { <PermanentRedirect path=format!("/blog/{slug}") /> }.into_any()
+                }
+                Some(Ok(BlogLookup::Gone { title })) => {
+                    #[cfg(feature = "ssr")]
+                    {
+                        let response = expect_context::<leptos_axum::ResponseOptions>();
+                        response.set_status(http::StatusCode::GONE);
+                    }
+                    view! {
+                        <div class="max-w-4xl mx-auto px-6 sm:px-8 lg:px-12 py-16 text-center">
+                            <h1 class="text-2xl font-bold text-gray-900 mb-4">
+                                "Post No Longer Available"
+                            </h1>
+                            <p class="text-gray-600 mb-8">
+                                {format!("\"{title}\" has been archived.")}
+                            </p>
+                            <A href="/blog">
+                                <span class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors">
+                                    "Back to Blog"
+                                </span>
+                            </A>
+                        </div>
+                    }
+                        .into_any()
+                }
+                Some(Ok(BlogLookup::Found(article))) => {
+                    let description = article.post.stats().meta_description;
+                    let post_id = article.post.id.clone();
                     view! {
-                        <Title text=format!("{} - Mike's Dev Blog", blog_post.title.clone()) />
-                        <Meta
-                            name="description"
-                            content=extract_meta_description(&blog_post.content.clone())
+                        <PostMetadata
+                            post=article.post.clone()
+                            description=description
+                            site_url=article.site_url.clone()
                         />
-
-                        <article class="max-w-4xl mx-auto px-6 sm:px-8 lg:px-12 py-16">
-                            // Blog Header with increased spacing
-                            <header class="mb-12 pb-8 border-b border-gray-200">
-                                // Title with increased margin
-                                <h1 class="text-4xl md:text-5xl font-bold text-gray-900 leading-tight mb-8">
-                                    {blog_post.title.clone()}
-                                </h1>
-
-                                // Meta information with increased spacing
-                                <div class="flex items-center text-gray-600 mb-8">
-                                    <div class="flex items-center">
-                                        <span class="text-sm font-medium">"by "</span>
-                                        <span class="text-sm font-semibold text-gray-900 ml-1">
-                                            {blog_post.author.clone()}
-                                        </span>
-                                    </div>
-                                    <span class="mx-3 text-gray-400">"•"</span>
-                                    <time
-                                        datetime=blog_post.created_at.clone().to_rfc3339()
-                                        class="text-sm"
-                                    >
-                                        {format_date_readable(blog_post.created_at)}
-                                    </time>
-                                    <span class="mx-3 text-gray-400">"•"</span>
-                                    <span class="text-sm">
-                                        {estimate_reading_time(&blog_post.content)} " min read"
-                                    </span>
-                                </div>
-
-                                // Tags with increased spacing
-                                {if blog_post.tags.is_empty() {
-                                    view! { <></> }
-                                    ().into_any()
-                                } else {
-                                    view! {
-                                        <div class="flex flex-wrap gap-3 mb-8">
-                                            {blog_post
-                                                .tags
-                                                .clone()
-                                                .iter()
-                                                .map(|tag| {
-                                                    view! {
-                                                        <span class="inline-flex items-center px-4 py-2 rounded-full text-sm font-medium bg-blue-100 text-blue-800 hover:bg-blue-200 transition-colors">
-                                                            {tag.clone()}
-                                                        </span>
-                                                    }
-                                                })
-                                                .collect_view()}
-                                        </div>
-                                    }
-                                        .into_any()
-                                }}
-                            </header>
-
-                            // Cover Image with increased spacing
-                            {blog_post
-                                .image_url
-                                .as_ref()
-                                .map(|url| {
-                                    view! {
-                                        <div class="mb-12">
-                                            <figure class="rounded-xl overflow-hidden shadow-lg">
-                                                <img
-                                                    src=url.clone()
-                                                    alt=format!("Cover image for {}", blog_post.title.clone())
-                                                    class="w-full h-auto object-cover"
-                                                    loading="eager"
-                                                />
-                                            </figure>
-                                        </div>
-                                    }
-                                        .into_any()
-                                })
-                                .unwrap_or_else(|| { view! { <div></div> }.into_any() })}
-
-                            // Blog Content with increased spacing and padding
-                            <div class="prose prose-lg prose-blue max-w-none">
-                                <div
-                                    class="blog-content px-4 py-8 leading-relaxed"
-                                    inner_html=blog_post.content.clone()
-                                ></div>
-                            </div>
-
-                            // Footer section with increased spacing
-                            <footer class="mt-16 pt-8 border-t border-gray-200">
-                                <div class="flex flex-col sm:flex-row sm:items-center sm:justify-between">
-                                    <div class="mb-4 sm:mb-0">
-                                        <p class="text-sm text-gray-600">
-                                            "Published on "
-                                            <time
-                                                datetime=blog_post.created_at.to_rfc3339()
-                                                class="font-medium"
-                                            >
-                                                {format_date_readable(blog_post.created_at)}
-                                            </time>
-                                        </p>
-                                        {if blog_post.updated_at == blog_post.created_at {
-                                            view! { <></> }
-                                            ().into_any()
-                                        } else {
-                                            view! {
-                                                <p class="text-sm text-gray-500 mt-1">
-                                                    "Last updated on "
-                                                    <time datetime=blog_post
-                                                        .updated_at
-                                                        .to_rfc3339()>
-                                                        {format_date_readable(blog_post.updated_at)}
-                                                    </time>
-                                                </p>
-                                            }
-                                                .into_any()
-                                        }}
-                                    </div>
-
-                                    <div class="flex space-x-4">
-                                        <A href="/blog">
-                                            <span class="inline-flex items-center px-4 py-2 border border-gray-300 text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 transition-colors">
-                                                <svg
-                                                    class="mr-2 h-4 w-4"
-                                                    fill="none"
-                                                    stroke="currentColor"
-                                                    viewBox="0 0 24 24"
-                                                >
-                                                    <path
-                                                        stroke-linecap="round"
-                                                        stroke-linejoin="round"
-                                                        stroke-width="2"
-                                                        d="M7 16l-4-4m0 0l4-4m-4 4h18"
-                                                    />
-                                                </svg>
-                                                "Back to Blog"
-                                            </span>
-                                        </A>
-
-                                        <button
-                                            class="inline-flex items-center px-4 py-2 border border-transparent text-sm font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors"
-                                            on:click=move |_| {
-                                                let clipboard = window().navigator().clipboard();
-                                                let url = window().location().href().unwrap_or_default();
-                                                let _ = clipboard.write_text(&url);
-                                            }
-                                        >
-                                            <svg
-                                                class="mr-2 h-4 w-4"
-                                                fill="none"
-                                                stroke="currentColor"
-                                                viewBox="0 0 24 24"
-                                            >
-                                                <path
-                                                    stroke-linecap="round"
-                                                    stroke-linejoin="round"
-                                                    stroke-width="2"
-                                                    d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z"
-                                                />
-                                            </svg>
-                                            "Share"
-                                        </button>
-                                    </div>
-                                </div>
-                            </footer>
-                        </article>
+                        <ArticleBody article=*article />
+                        <RelatedPosts post_id=post_id />
                     }
                         .into_any()
                 }
                 Some(Err(error)) => {
+                    #[cfg(feature = "ssr")]
+                    {
+                        let response = expect_context::<leptos_axum::ResponseOptions>();
+                        response.set_status(http::StatusCode::NOT_FOUND);
+                    }
                     view! {
                         <div class="max-w-4xl mx-auto px-6 sm:px-8 lg:px-12 py-16">
                             <div class="text-center">
@@ -289,47 +134,185 @@ pub fn BlogDetail() -> impl IntoView {
     }
 }
 
-// Helper function to estimate reading time
-fn estimate_reading_time(content: &str) -> u32 {
-    let word_count = content.split_whitespace().count();
-    let reading_time = (word_count as f32 / 225.0).ceil() as u32;
-    std::cmp::max(1, reading_time)
-}
+/// Header, cover, content, footer and table of contents of a rendered post. Shared by
+/// the post page and draft previews, which add their own metadata around it.
+#[component]
+#[allow(clippy::too_many_lines)]
+#[allow(clippy::must_use_candidate)]
+pub fn ArticleBody(article: BlogArticle) -> impl IntoView {
+    let BlogArticle {
+        post: blog_post,
+        content,
+        series,
+        site_url: _,
+    } = article;
+    // Short posts without subheadings don't need navigation
+    let show_toc = blog_post.show_toc && !content.toc.is_empty();
+    let stats = blog_post.stats();
 
-// Helper function to extract meta description from content
-fn extract_meta_description(content: &str) -> String {
-    let text_content = content
-        .replace("<p>", "")
-        .replace("</p>", " ")
-        .replace("<br>", " ")
-        .replace("<br/>", " ")
-        .replace("<h1>", "")
-        .replace("</h1>", " ")
-        .replace("<h2>", "")
-        .replace("</h2>", " ")
-        .replace("<h3>", "")
-        .replace("</h3>", " ")
-        .replace("<strong>", "")
-        .replace("</strong>", "")
-        .replace("<em>", "")
-        .replace("</em>", "");
+    view! {
 
-    let cleaned_text: String = text_content
-        .chars()
-        .filter(|c| !c.is_control() || c.is_whitespace())
-        .collect::<String>()
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ");
+        <div class="max-w-7xl mx-auto xl:flex xl:justify-center xl:gap-12">
+            <article class="w-full max-w-4xl mx-auto xl:mx-0 px-6 sm:px-8 lg:px-12 py-16">
+                // Blog Header with increased spacing
+                <header class="mb-12 pb-8 border-b border-gray-200">
+                    // Title with increased margin
+                    <h1 class="text-4xl md:text-5xl font-bold text-gray-900 leading-tight mb-8">
+                        {blog_post.title.clone()}
+                    </h1>
 
-    if cleaned_text.len() <= 160 {
-        cleaned_text
-    } else {
-        let truncated = &cleaned_text[..160];
-        if let Some(last_space) = truncated.rfind(' ') {
-            format!("{}...", &truncated[..last_space])
-        } else {
-            format!("{truncated}...")
-        }
+                    // Meta information with increased spacing
+                    <div class="flex items-center text-gray-600 mb-8">
+                        <div class="flex items-center">
+                            <span class="text-sm font-medium">"by "</span>
+                            <span class="text-sm font-semibold text-gray-900 ml-1">
+                                {blog_post.author.clone()}
+                            </span>
+                        </div>
+                        <span class="mx-3 text-gray-400">"•"</span>
+                        <time
+                            datetime=blog_post.created_at.clone().to_rfc3339()
+                            class="text-sm"
+                        >
+                            {format_date_readable(blog_post.created_at)}
+                        </time>
+                        <span class="mx-3 text-gray-400">"•"</span>
+                        <span class="text-sm">
+                            {stats.reading_time_minutes} " min read"
+                        </span>
+                    </div>
+
+                    // Tags with increased spacing
+                    {if blog_post.tags.is_empty() {
+                        view! { <></> }
+                        ().into_any()
+                    } else {
+                        view! {
+                            <div class="flex flex-wrap gap-3 mb-8">
+                                <TagChips tags=blog_post.tags.clone() large=true />
+                            </div>
+                        }
+                            .into_any()
+                    }}
+                </header>
+
+                {series.map(|series| view! { <SeriesParts series=series /> })}
+
+                // Cover Image with increased spacing
+                {blog_post
+                    .image_url
+                    .as_ref()
+                    .map(|url| {
+                        view! {
+                            <div class="mb-12">
+                                <figure class="rounded-xl overflow-hidden shadow-lg">
+                                    <img
+                                        src=url.clone()
+                                        alt=format!("Cover image for {}", blog_post.title.clone())
+                                        class="w-full h-auto object-cover"
+                                        loading="eager"
+                                    />
+                                </figure>
+                            </div>
+                        }
+                            .into_any()
+                    })
+                    .unwrap_or_else(|| { view! { <div></div> }.into_any() })}
+
+                // Blog Content with increased spacing and padding
+                <div class="prose prose-lg prose-blue max-w-none">
+                    <div
+                        class="blog-content px-4 py-8 leading-relaxed"
+                        inner_html=content.html
+                    ></div>
+                </div>
+
+                // Footer section with increased spacing
+                <footer class="mt-16 pt-8 border-t border-gray-200">
+                    <div class="flex flex-col sm:flex-row sm:items-center sm:justify-between">
+                        <div class="mb-4 sm:mb-0">
+                            <p class="text-sm text-gray-600">
+                                "Published on "
+                                <time
+                                    datetime=blog_post.created_at.to_rfc3339()
+                                    class="font-medium"
+                                >
+                                    {format_date_readable(blog_post.created_at)}
+                                </time>
+                            </p>
+                            {if blog_post.updated_at == blog_post.created_at {
+                                view! { <></> }
+                                ().into_any()
+                            } else {
+                                view! {
+                                    <p class="text-sm text-gray-500 mt-1">
+                                        "Last updated on "
+                                        <time datetime=blog_post
+                                            .updated_at
+                                            .to_rfc3339()>
+                                            {format_date_readable(blog_post.updated_at)}
+                                        </time>
+                                    </p>
+                                }
+                                    .into_any()
+                            }}
+                        </div>
+
+                        <div class="flex space-x-4">
+                            <A href="/blog">
+                                <span class="inline-flex items-center px-4 py-2 border border-gray-300 text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 transition-colors">
+                                    <svg
+                                        class="mr-2 h-4 w-4"
+                                        fill="none"
+                                        stroke="currentColor"
+                                        viewBox="0 0 24 24"
+                                    >
+                                        <path
+                                            stroke-linecap="round"
+                                            stroke-linejoin="round"
+                                            stroke-width="2"
+                                            d="M7 16l-4-4m0 0l4-4m-4 4h18"
+                                        />
+                                    </svg>
+                                    "Back to Blog"
+                                </span>
+                            </A>
+
+                            <button
+                                class="inline-flex items-center px-4 py-2 border border-transparent text-sm font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors"
+                                on:click=move |_| {
+                                    let clipboard = window().navigator().clipboard();
+                                    let url = window().location().href().unwrap_or_default();
+                                    let _ = clipboard.write_text(&url);
+                                }
+                            >
+                                <svg
+                                    class="mr-2 h-4 w-4"
+                                    fill="none"
+                                    stroke="currentColor"
+                                    viewBox="0 0 24 24"
+                                >
+                                    <path
+                                        stroke-linecap="round"
+                                        stroke-linejoin="round"
+                                        stroke-width="2"
+                                        d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z"
+                                    />
+                                </svg>
+                                "Share"
+                            </button>
+                        </div>
+                    </div>
+                </footer>
+            </article>
+            {show_toc
+                .then(|| {
+                    view! {
+                        <aside class="hidden xl:block w-64 shrink-0 py-16">
+                            <TableOfContents entries=content.toc />
+                        </aside>
+                    }
+                })}
+        </div>
     }
 }
@@ -1,5 +1,6 @@
 use crate::api::blogs::get_blog_server;
 use crate::models::blog::BlogPost;
+use crate::rendering::render_markdown;
 use js_sys::wasm_bindgen;
 use leptos::prelude::*;
 use leptos::web_sys;
@@ -482,9 +483,7 @@ pub fn BlogEditor() -> impl IntoView {
                                                         {move || {
                                                             if !content.get().is_empty() {
                                                                 view! {
-                                                                    <div inner_html=convert_markdown_to_html(
-                                                                        &content.get(),
-                                                                    )></div>
+                                                                    <div inner_html=render_markdown(&content.get()).html></div>
                                                                 }
                                                                     .into_view()
                                                             } else {
@@ -850,34 +849,3 @@ pub fn BlogEditor() -> impl IntoView {
         </div>
     }
 }
-
-// Helper function to convert markdown code blocks to HTML with Prism classes
-fn convert_markdown_to_html(content: &str) -> String {
-    use regex::Regex;
-
-    let mut html = content.to_string();
-
-    // Convert markdown code blocks to HTML with Prism classes
-    if let Ok(re) = Regex::new(r"```(\w+)\n([\s\S]*?)\n```") {
-        html = re
-            .replace_all(&html, |caps: &regex::Captures| {
-                let language = &caps[1];
-                let code = &caps[2];
-                format!(
-                    "<pre><code class=\"language-{}\">{}</code></pre>",
-                    language,
-                    html_escape::encode_text(code)
-                )
-            })
-            .to_string();
-    }
-
-    // Convert basic markdown elements
-    html = html.replace("**", "<strong>").replace("**", "</strong>");
-    html = html.replace("*", "<em>").replace("*", "</em>");
-    html = html.replace("\n\n", "</p><p>");
-    html = format!("<p>{}</p>", html);
-    html = html.replace("<p></p>", "");
-
-    html
-}
@@ -3,34 +3,36 @@
 #![allow(clippy::cast_sign_loss)]
 #![allow(clippy::map_unwrap_or)]
 #![allow(clippy::cast_precision_loss)]
-use leptos::{logging, prelude::*};
+use std::fmt::Write;
 
-use crate::{api::blogs::get_blogs_server, models::blog::BlogPost};
-use leptos_router::components::A;
+use leptos::prelude::*;
+use leptos_meta::{Link, Title};
+
+use crate::{
+    api::blogs::get_blog_index_server,
+    components::tag_chips::TagChips,
+    models::{
+        blog::BlogPost,
+        blog_index::{BlogIndexPage, BlogIndexQuery, PostSort, POSTS_PER_PAGE},
+    },
+    utils::url::absolute_url,
+};
+use leptos_router::{
+    components::{Form, A},
+    hooks::use_query_map,
+};
 #[component]
 #[allow(clippy::must_use_candidate)]
 #[allow(clippy::too_many_lines)]
 pub fn BlogList() -> impl IntoView {
-    // Create an Action for fetching blogs instead of Resource
-    let fetch_blogs_action = Action::new(|(): &()| async move { get_blogs_server().await });
-
-    // Create an Action for refreshing blogs
-    let refresh_blogs_action = Action::new(|(): &()| async move { get_blogs_server().await });
-
-    // Trigger initial fetch when component mounts
-    Effect::new(move |_| {
-        fetch_blogs_action.dispatch(());
-    });
-
-    // Handle refresh action results
-    Effect::new(move |_| {
-        if let Some(result) = refresh_blogs_action.value().get() {
-            if result.is_ok() {
-                // Optionally show success message or handle refresh completion
-                logging::log!("Blog list refreshed successfully");
-            }
-        }
-    });
+    // Page, filters and order live in the query string, so every state is a shareable URL
+    let query_map = use_query_map();
+    let index_query =
+        move || query_map.with(|params| BlogIndexQuery::from_params(|key| params.get(key)));
+    // Blocking so the posts are part of the server-rendered HTML, and hydrate from it
+    let blogs_resource = Resource::new_blocking(index_query, get_blog_index_server);
+    // Set while a refetch is running; the previous posts stay on screen meanwhile
+    let (refreshing, set_refreshing) = signal(false);
 
     view! {
         <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
@@ -45,16 +47,11 @@ pub fn BlogList() -> impl IntoView {
                     <button
                         type="button"
                         class="inline-flex items-center px-4 py-2 border border-gray-300 shadow-sm text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500 disabled:opacity-50 disabled:cursor-not-allowed"
-                        on:click=move |_| {
-                            refresh_blogs_action.dispatch(());
-                        }
-                        disabled=move || {
-                            refresh_blogs_action.pending().get()
-                                || fetch_blogs_action.pending().get()
-                        }
+                        on:click=move |_| blogs_resource.refetch()
+                        disabled=move || refreshing.get()
                     >
                         {move || {
-                            if refresh_blogs_action.pending().get() {
+                            if refreshing.get() {
                                 view! {
                                     <svg
                                         class="animate-spin -ml-1 mr-2 h-4 w-4 text-gray-700"
@@ -99,26 +96,48 @@ pub fn BlogList() -> impl IntoView {
                             }
                         }}
                     </button>
-
-                // www.w3.org/2000/svg"
                 </div>
             </div>
 
             // Content area
             <div class="space-y-8">
                 {move || {
-                    let current_result = if let Some(refresh_result) = refresh_blogs_action
-                        .value()
+                    refreshing
                         .get()
-                    {
-                        Some(refresh_result)
-                    } else {
-                        fetch_blogs_action.value().get()
-                    };
-                    if fetch_blogs_action.pending().get() && current_result.is_none() {
-                        // Get the most recent result from either action
-
-                        // Show loading state
+                        .then(|| {
+                            view! {
+                                <div class="bg-blue-50 border border-blue-200 rounded-md p-4">
+                                    <div class="flex items-center">
+                                        <svg
+                                            class="animate-spin h-5 w-5 text-blue-600 mr-3"
+                                            xmlns="http://www.w3.org/2000/svg"
+                                            fill="none"
+                                            viewBox="0 0 24 24"
+                                        >
+                                            <circle
+                                                class="opacity-25"
+                                                cx="12"
+                                                cy="12"
+                                                r="10"
+                                                stroke="currentColor"
+                                                stroke-width="4"
+                                            ></circle>
+                                            <path
+                                                class="opacity-75"
+                                                fill="currentColor"
+                                                d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"
+                                            ></path>
+                                        </svg>
+                                        <span class="text-sm text-blue-800">
+                                            "Refreshing blog posts..."
+                                        </span>
+                                    </div>
+                                </div>
+                            }
+                        })
+                }}
+                <Transition
+                    fallback=move || {
                         view! {
                             <div class="text-center py-12">
                                 <div class="inline-flex items-center">
@@ -148,185 +167,388 @@ pub fn BlogList() -> impl IntoView {
                                 </div>
                             </div>
                         }
-                            .into_any()
-                    } else if let Some(result) = current_result {
-                        match result {
-                            Ok(posts) => {
-                                if posts.is_empty() {
-                                    view! {
-                                        <div class="text-center py-16">
-                                            <svg
-                                                class="mx-auto h-12 w-12 text-gray-400"
-                                                fill="none"
-                                                viewBox="0 0 24 24"
-                                                stroke="currentColor"
-                                                aria-hidden="true"
-                                            >
-                                                <path
-                                                    stroke-linecap="round"
-                                                    stroke-linejoin="round"
-                                                    stroke-width="2"
-                                                    d="M9 12h6m-6 4h6m2 5H7a2 2 0 01-2-2V5a2 2 0 012-2h5.586a1 1 0 01.707.293l5.414 5.414a1 1 0 01.293.707V19a2 2 0 01-2 2z"
-                                                />
-                                            </svg>
-                                            <h3 class="mt-2 text-2xl font-medium text-gray-900">
-                                                "No blog posts yet"
-                                            </h3>
-                                            <p class="mt-1 text-lg text-gray-500">
-                                                "Get started by writing your first blog post."
-                                            </p>
-                                            <div class="mt-6">
-                                                <A
-                                                    href="/blog-editor"
-                                                    attr:class="inline-flex items-center px-6 py-3 border border-transparent shadow-sm text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700"
-                                                >
-                                                    <svg
-                                                        xmlns="http://www.w3.org/2000/svg"
-                                                        class="h-5 w-5 mr-2"
-                                                        fill="none"
-                                                        viewBox="0 0 24 24"
-                                                        stroke="currentColor"
-                                                    >
-                                                        <path
-                                                            stroke-linecap="round"
-                                                            stroke-linejoin="round"
-                                                            stroke-width="2"
-                                                            d="M12 4v16m8-8H4"
-                                                        />
-                                                    </svg>
-                                                    "Write your first post"
-                                                </A>
-                                            </div>
-                                        </div>
-                                    }
-                                        .into_any()
-                                } else if refresh_blogs_action.pending().get() {
+                    }
+                    set_pending=set_refreshing
+                >
+                    {move || {
+                        blogs_resource
+                            .get()
+                            .map(|result| match result {
+                                Ok(index) => view! { <BlogIndex index=index /> }.into_any(),
+                                Err(err) => {
                                     view! {
-                                        <div class="space-y-8">
-                                            <div class="bg-blue-50 border border-blue-200 rounded-md p-4">
-                                                <div class="flex items-center">
-                                                    <svg
-                                                        class="animate-spin h-5 w-5 text-blue-600 mr-3"
-                                                        xmlns="http://www.w3.org/2000/svg"
-                                                        fill="none"
-                                                        viewBox="0 0 24 24"
-                                                    >
-                                                        <circle
-                                                            class="opacity-25"
-                                                            cx="12"
-                                                            cy="12"
-                                                            r="10"
-                                                            stroke="currentColor"
-                                                            stroke-width="4"
-                                                        ></circle>
-                                                        <path
-                                                            class="opacity-75"
+                                        <Title text="Blog - Mike's Dev Blog" />
+                                        <div class="text-center py-12">
+                                            <div class="bg-red-50 border-l-4 border-red-400 p-6 rounded-md max-w-lg mx-auto">
+                                                <div class="flex">
+                                                    <div class="flex-shrink-0">
+                                                        <svg
+                                                            class="h-5 w-5 text-red-400"
+                                                            xmlns="http://www.w3.org/2000/svg"
+                                                            viewBox="0 0 20 20"
                                                             fill="currentColor"
-                                                            d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"
-                                                        ></path>
-                                                    </svg>
-                                                    <span class="text-sm text-blue-800">
-                                                        "Refreshing blog posts..."
-                                                    </span>
+                                                        >
+                                                            <path
+                                                                fill-rule="evenodd"
+                                                                d="M10 18a8 8 0 100-16 8 8 0 000 16zM8.707 7.293a1 1 0 00-1.414 1.414L8.586 10l-1.293 1.293a1 1 0 101.414 1.414L10 11.414l1.293 1.293a1 1 0 001.414-1.414L11.414 10l1.293-1.293a1 1 0 00-1.414-1.414L10 8.586 8.707 7.293z"
+                                                                clip-rule="evenodd"
+                                                            />
+                                                        </svg>
+                                                    </div>
+                                                    <div class="ml-3">
+                                                        <h3 class="text-sm font-medium text-red-800">
+                                                            "Error Loading Blog Posts"
+                                                        </h3>
+                                                        <p class="mt-2 text-sm text-red-700">
+                                                            {format!("Failed to load blog posts: {err}")}
+                                                        </p>
+                                                        <div class="mt-4">
+                                                            <button
+                                                                type="button"
+                                                                class="inline-flex items-center px-3 py-2 border border-transparent text-sm leading-4 font-medium rounded-md text-red-700 bg-red-100 hover:bg-red-200 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-red-500"
+                                                                on:click=move |_| {
+                                                                    blogs_resource.refetch();
+                                                                }
+                                                            >
+                                                                "Try Again"
+                                                            </button>
+                                                        </div>
+                                                    </div>
                                                 </div>
                                             </div>
-                                            <div class="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
-                                                {posts
-                                                    .into_iter()
-                                                    .map(|post| {
-                                                        view! { <BlogPostCard post=post /> }
-                                                    })
-                                                    .collect_view()}
-                                            </div>
-                                        </div>
-                                    }
-                                        .into_any()
-                                } else {
-                                    view! {
-                                        <div class="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
-                                            {posts
-                                                .into_iter()
-                                                .map(|post| {
-                                                    view! { <BlogPostCard post=post /> }
-                                                })
-                                                .collect_view()}
                                         </div>
                                     }
                                         .into_any()
                                 }
-                            }
-                            Err(err) => {
+                            })
+                    }}
+                </Transition>
+            </div>
+        </div>
+    }
+}
+
+/// Heading, filters, posts and pagination of one page of the blog index.
+#[component]
+#[allow(clippy::too_many_lines)]
+fn BlogIndex(index: BlogIndexPage) -> impl IntoView {
+    let query = index.query.clone();
+    let out_of_range = query.page > index.page_count.max(1);
+    #[cfg(feature = "ssr")]
+    {
+        if out_of_range {
+            let response = expect_context::<leptos_axum::ResponseOptions>();
+            response.set_status(http::StatusCode::NOT_FOUND);
+        }
+    }
+
+    let prev = (query.page > 1 && !out_of_range).then(|| query.with_page(query.page - 1).path());
+    let next = (query.page < index.page_count).then(|| query.with_page(query.page + 1).path());
+    let title = page_title(&index);
+    let summary = results_summary(&index);
+    let filtered = query.is_filtered();
+    // Clearing the filters keeps the chosen order
+    let clear_path = BlogIndexQuery {
+        sort: query.sort,
+        ..BlogIndexQuery::default()
+    }
+    .path();
+
+    let content = if out_of_range {
+        view! {
+            <div class="text-center py-16">
+                <h3 class="text-2xl font-medium text-gray-900">"Page not found"</h3>
+                <p class="mt-1 text-lg text-gray-500">
+                    {format!("There is no page {} of these posts.", query.page)}
+                </p>
+                <div class="mt-6">
+                    <A
+                        href=query.with_page(1).path()
+                        attr:class="inline-flex items-center px-6 py-3 border border-transparent shadow-sm text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700"
+                    >
+                        "Go to the first page"
+                    </A>
+                </div>
+            </div>
+        }
+            .into_any()
+    } else if index.posts.is_empty() && filtered {
+        view! {
+            <div class="text-center py-16">
+                <h3 class="text-2xl font-medium text-gray-900">"No posts match these filters"</h3>
+                <p class="mt-1 text-lg text-gray-500">"Try another tag or year."</p>
+                <div class="mt-6">
+                    <A
+                        href=clear_path.clone()
+                        attr:class="inline-flex items-center px-6 py-3 border border-transparent shadow-sm text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700"
+                    >
+                        "Clear filters"
+                    </A>
+                </div>
+            </div>
+        }
+            .into_any()
+    } else if index.posts.is_empty() {
+        view! {
+            <div class="text-center py-16">
+                <svg
+                    class="mx-auto h-12 w-12 text-gray-400"
+                    fill="none"
+                    viewBox="0 0 24 24"
+                    stroke="currentColor"
+                    aria-hidden="true"
+                >
+                    <path
+                        stroke-linecap="round"
+                        stroke-linejoin="round"
+                        stroke-width="2"
+                        d="M9 12h6m-6 4h6m2 5H7a2 2 0 01-2-2V5a2 2 0 012-2h5.586a1 1 0 01.707.293l5.414 5.414a1 1 0 01.293.707V19a2 2 0 01-2 2z"
+                    />
+                </svg>
+                <h3 class="mt-2 text-2xl font-medium text-gray-900">"No blog posts yet"</h3>
+                <p class="mt-1 text-lg text-gray-500">
+                    "Get started by writing your first blog post."
+                </p>
+                <div class="mt-6">
+                    <A
+                        href="/blog-editor"
+                        attr:class="inline-flex items-center px-6 py-3 border border-transparent shadow-sm text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700"
+                    >
+                        <svg
+                            xmlns="http://www.w3.org/2000/svg"
+                            class="h-5 w-5 mr-2"
+                            fill="none"
+                            viewBox="0 0 24 24"
+                            stroke="currentColor"
+                        >
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M12 4v16m8-8H4"
+                            />
+                        </svg>
+                        "Write your first post"
+                    </A>
+                </div>
+            </div>
+        }
+            .into_any()
+    } else {
+        view! {
+            <div class="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
+                {index
+                    .posts
+                    .clone()
+                    .into_iter()
+                    .map(|post| view! { <BlogPostCard post=post /> })
+                    .collect_view()}
+            </div>
+            <Pagination index=index.clone() />
+        }
+        .into_any()
+    };
+
+    view! {
+        <Title text=title />
+        {prev.map(|path| view! { <Link rel="prev" href=absolute_url(&index.site_url, &path) /> })}
+        {next.map(|path| view! { <Link rel="next" href=absolute_url(&index.site_url, &path) /> })}
+
+        <FilterBar index=index.clone() />
+        <div class="flex flex-wrap items-center justify-between gap-2 text-sm text-gray-600">
+            <p>{summary}</p>
+            {filtered
+                .then(|| {
+                    view! {
+                        <A
+                            href=clear_path
+                            attr:class="font-medium text-blue-600 hover:text-blue-800"
+                        >
+                            "Clear filters"
+                        </A>
+                    }
+                })}
+        </div>
+        {content}
+    }
+}
+
+/// Tag, year and order controls. A plain GET form, so it also works before hydration and
+/// always lands on the first page of the new selection.
+#[component]
+fn FilterBar(index: BlogIndexPage) -> impl IntoView {
+    let query = index.query;
+    let select_class = "mt-1 block w-full rounded-md border-gray-300 py-2 pl-3 pr-10 text-sm shadow-sm focus:border-blue-500 focus:ring-blue-500";
+
+    view! {
+        <Form method="GET" action="/blog">
+            <div class="grid gap-4 sm:grid-cols-4 items-end bg-gray-50 border border-gray-200 rounded-lg p-4">
+                <label class="block text-sm font-medium text-gray-700">
+                    "Tag"
+                    <select name="tag" class=select_class>
+                        <option value="" selected=query.tag.is_none()>
+                            "All tags"
+                        </option>
+                        {index
+                            .tags
+                            .into_iter()
+                            .map(|tag| {
+                                let selected = query.tag.as_ref() == Some(&tag.slug);
                                 view! {
-                                    <div class="text-center py-12">
-                                        <div class="bg-red-50 border-l-4 border-red-400 p-6 rounded-md max-w-lg mx-auto">
-                                            <div class="flex">
-                                                <div class="flex-shrink-0">
-                                                    <svg
-                                                        class="h-5 w-5 text-red-400"
-                                                        xmlns="http://www.w3.org/2000/svg"
-                                                        viewBox="0 0 20 20"
-                                                        fill="currentColor"
-                                                    >
-                                                        <path
-                                                            fill-rule="evenodd"
-                                                            d="M10 18a8 8 0 100-16 8 8 0 000 16zM8.707 7.293a1 1 0 00-1.414 1.414L8.586 10l-1.293 1.293a1 1 0 101.414 1.414L10 11.414l1.293 1.293a1 1 0 001.414-1.414L11.414 10l1.293-1.293a1 1 0 00-1.414-1.414L10 8.586 8.707 7.293z"
-                                                            clip-rule="evenodd"
-                                                        />
-                                                    </svg>
-                                                </div>
-                                                <div class="ml-3">
-                                                    <h3 class="text-sm font-medium text-red-800">
-                                                        "Error Loading Blog Posts"
-                                                    </h3>
-                                                    <p class="mt-2 text-sm text-red-700">
-                                                        {format!("Failed to load blog posts: {err}")}
-                                                    </p>
-                                                    <div class="mt-4">
-                                                        <button
-                                                            type="button"
-                                                            class="inline-flex items-center px-3 py-2 border border-transparent text-sm leading-4 font-medium rounded-md text-red-700 bg-red-100 hover:bg-red-200 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-red-500"
-                                                            on:click=move |_| {
-                                                                fetch_blogs_action.dispatch(());
-                                                            }
-                                                        >
-                                                            "Try Again"
-                                                        </button>
-                                                    </div>
-                                                </div>
-                                            </div>
-                                        </div>
-                                    </div>
+                                    <option value=tag.slug selected=selected>
+                                        {format!("{} ({})", tag.tag, tag.count)}
+                                    </option>
                                 }
-                                    .into_any()
-                            }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <label class="block text-sm font-medium text-gray-700">
+                    "Year"
+                    <select name="year" class=select_class>
+                        <option value="" selected=query.year.is_none()>
+                            "All years"
+                        </option>
+                        {index
+                            .years
+                            .into_iter()
+                            .map(|year| {
+                                view! {
+                                    <option value=year.to_string() selected=query.year == Some(year)>
+                                        {year.to_string()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <label class="block text-sm font-medium text-gray-700">
+                    "Sort"
+                    <select name="sort" class=select_class>
+                        {PostSort::all()
+                            .into_iter()
+                            .map(|sort| {
+                                view! {
+                                    <option value=sort.as_str() selected=query.sort == sort>
+                                        {sort.label()}
+                                    </option>
+                                }
+                            })
+                            .collect_view()}
+                    </select>
+                </label>
+                <button
+                    type="submit"
+                    class="inline-flex justify-center items-center px-4 py-2 border border-transparent shadow-sm text-sm font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-blue-500"
+                >
+                    "Apply"
+                </button>
+            </div>
+        </Form>
+    }
+}
+
+/// Previous, numbered and next page links.
+#[component]
+fn Pagination(index: BlogIndexPage) -> impl IntoView {
+    let query = index.query.clone();
+    if index.page_count <= 1 {
+        return ().into_any();
+    }
+
+    let link_class = "inline-flex items-center px-4 py-2 border border-gray-300 text-sm font-medium rounded-md text-gray-700 bg-white hover:bg-gray-50";
+    let prev = (query.page > 1).then(|| query.with_page(query.page - 1).path());
+    let next = (query.page < index.page_count).then(|| query.with_page(query.page + 1).path());
+
+    view! {
+        <nav class="flex items-center justify-center gap-2 pt-4" aria-label="Pagination">
+            {prev
+                .map(|path| {
+                    view! {
+                        <A href=path attr:rel="prev" attr:class=link_class>
+                            "Previous"
+                        </A>
+                    }
+                })}
+            {index
+                .page_links()
+                .into_iter()
+                .map(|page| match page {
+                    Some(page) if page == query.page => {
+                        view! {
+                            <span
+                                class="inline-flex items-center px-4 py-2 border border-blue-600 text-sm font-medium rounded-md text-white bg-blue-600"
+                                aria-current="page"
+                            >
+                                {page.to_string()}
+                            </span>
                         }
-                    } else {
+                            .into_any()
+                    }
+                    Some(page) => {
                         view! {
-                            <div class="text-center py-12">
-                                <p class="text-xl text-gray-600">"Initializing..."</p>
-                            </div>
+                            <A href=query.with_page(page).path() attr:class=link_class>
+                                {page.to_string()}
+                            </A>
                         }
                             .into_any()
                     }
-                }}
-            </div>
-        </div>
+                    None => view! { <span class="px-2 text-gray-500">"…"</span> }.into_any(),
+                })
+                .collect_view()}
+            {next
+                .map(|path| {
+                    view! {
+                        <A href=path attr:rel="next" attr:class=link_class>
+                            "Next"
+                        </A>
+                    }
+                })}
+        </nav>
     }
+        .into_any()
 }
 
-#[component]
-fn BlogPostCard(post: BlogPost) -> impl IntoView {
-    let formatted_date = post.created_at.format("%B %d, %Y").to_string();
-    let summary = if post.content.len() > 200 {
-        let mut truncated = post.content.chars().take(200).collect::<String>();
-        // Try to break at word boundary
-        if let Some(last_space) = truncated.rfind(' ') {
-            truncated.truncate(last_space);
-        }
-        format!("{truncated}...")
+/// Document title naming the active filters and page.
+fn page_title(index: &BlogIndexPage) -> String {
+    let mut title = String::from("Blog");
+    if let Some(tag) = index.tag_name.as_ref().or(index.query.tag.as_ref()) {
+        let _ = write!(title, " - {tag}");
+    }
+    if let Some(year) = index.query.year {
+        let _ = write!(title, " - {year}");
+    }
+    if index.query.page > 1 {
+        let _ = write!(title, " - Page {}", index.query.page);
+    }
+    title.push_str(" - Mike's Dev Blog");
+    title
+}
+
+/// "Showing 13-24 of 40 posts tagged Rust from 2024".
+fn results_summary(index: &BlogIndexPage) -> String {
+    let first = index.query.page.saturating_sub(1) as usize * POSTS_PER_PAGE as usize + 1;
+    let mut summary = if index.posts.is_empty() {
+        format!("{} posts", index.total)
     } else {
-        post.content.clone()
+        let last = first + index.posts.len() - 1;
+        format!("Showing {first}-{last} of {} posts", index.total)
     };
+    if let Some(tag) = index.tag_name.as_ref().or(index.query.tag.as_ref()) {
+        let _ = write!(summary, " tagged {tag}");
+    }
+    if let Some(year) = index.query.year {
+        let _ = write!(summary, " from {year}");
+    }
+    summary
+}
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn BlogPostCard(post: BlogPost) -> impl IntoView {
+    let formatted_date = post.created_at.format("%B %d, %Y").to_string();
+    let stats = post.stats();
+    let path = post.path();
 
     view! {
         <article class="group flex flex-col overflow-hidden rounded-lg shadow-lg hover:shadow-xl transition-all duration-300 transform hover:-translate-y-1 bg-white">
@@ -360,37 +582,15 @@ fn BlogPostCard(post: BlogPost) -> impl IntoView {
                 <div class="flex-1">
                     // Tags
                     <div class="flex items-center gap-2 mb-3">
-                        {post
-                            .tags
-                            .iter()
-                            .take(3)
-                            .map(|tag| {
-                                view! {
-                                    <span class="inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium bg-blue-100 text-blue-800 hover:bg-blue-200 transition-colors">
-                                        {tag.clone()}
-                                    </span>
-                                }
-                            })
-                            .collect_view()}
-                        {if post.tags.len() > 3 {
-                            view! {
-                                <span class="text-xs text-gray-500">
-                                    {format!("+{} more", post.tags.len() - 3)}
-                                </span>
-                            }
-                                .into_any()
-                        } else {
-                            view! { <></> }
-                            ().into_any()
-                        }}
+                        <TagChips tags=post.tags.clone() limit=3 />
                     </div>
 
                     // Title and content
-                    <A href=format!("/blog/{}", post.id) attr:class="block">
+                    <A href=path attr:class="block">
                         <h3 class="text-xl font-semibold text-gray-900 group-hover:text-blue-600 transition-colors duration-300 line-clamp-2">
                             {post.title.clone()}
                         </h3>
-                        <p class="mt-3 text-base text-gray-500 line-clamp-3" inner_html=summary></p>
+                        <p class="mt-3 text-base text-gray-500 line-clamp-3">{stats.excerpt}</p>
                     </A>
                 </div>
 
@@ -409,13 +609,7 @@ fn BlogPostCard(post: BlogPost) -> impl IntoView {
                         <div class="flex space-x-1 text-sm text-gray-500">
                             <time datetime=post.created_at.to_rfc3339()>{formatted_date}</time>
                             <span aria-hidden="true">"·"</span>
-                            <span>
-                                {format!(
-                                    "{} min read",
-                                    (post.content.split_whitespace().count() as f32 / 200.0).ceil()
-                                        as u32,
-                                )}
-                            </span>
+                            <span>{stats.reading_time_minutes}" min read"</span>
                         </div>
                     </div>
                 </div>
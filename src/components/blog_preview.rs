@@ -0,0 +1,111 @@
+use leptos::prelude::*;
+use leptos::server::Resource;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::api::previews::get_preview_server;
+use crate::components::blog_detail::ArticleBody;
+use crate::constants::site;
+use crate::models::preview::PreviewArticle;
+use crate::utils::date_formatter::format_date_readable;
+use leptos::suspense::Suspense;
+
+/// Draft preview at `/blog/preview/:token`: the post revision the signed link was
+/// created for, under a banner, and kept out of search engines and caches.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn BlogPreview() -> impl IntoView {
+    let params = use_params_map();
+    let token = move || params.with(|params| params.get("token").unwrap_or_default());
+
+    // Blocking so that invalid links can set the response status during SSR
+    let preview_resource = Resource::new_blocking(token, |token| async move {
+        if token.is_empty() {
+            return Err("Preview link is malformed".to_string());
+        }
+        get_preview_server(token).await.map_err(|e| e.to_string())
+    });
+
+    #[cfg(feature = "ssr")]
+    {
+        use http::{
+            header::{CACHE_CONTROL, REFERRER_POLICY},
+            HeaderName, HeaderValue,
+        };
+
+        let response = expect_context::<leptos_axum::ResponseOptions>();
+        response.insert_header(
+            HeaderName::from_static("x-robots-tag"),
+            HeaderValue::from_static("noindex, nofollow"),
+        );
+        response.insert_header(CACHE_CONTROL, HeaderValue::from_static("private, no-store"));
+        // The token must not leak to other sites through links in the draft
+        response.insert_header(REFERRER_POLICY, HeaderValue::from_static("no-referrer"));
+    }
+
+    view! {
+        <Meta name="robots" content="noindex, nofollow" />
+        <Meta name="referrer" content="no-referrer" />
+
+        <Suspense fallback=move || {
+            view! {
+                <div class="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-16">
+                    <div class="animate-pulse h-64 bg-gray-200 rounded"></div>
+                </div>
+            }
+        }>
+            {move || match preview_resource.get() {
+                Some(Ok(PreviewArticle { article, expires_at })) => {
+                    let revision = article.post.updated_at;
+                    view! {
+                        <Title text=format!("Preview: {} - {}", article.post.title, site::TITLE) />
+                        <div
+                            class="sticky top-0 z-40 border-b border-amber-300 bg-amber-100 px-6 py-3 text-center text-sm text-amber-900"
+                            role="status"
+                        >
+                            <span class="font-semibold">"Draft preview"</span>
+                            " of the revision from "
+                            <time datetime=revision.to_rfc3339()>
+                                {format_date_readable(revision)}
+                            </time>
+                            ". This page is not public; the link expires on "
+                            <time datetime=expires_at.to_rfc3339()>
+                                {format_date_readable(expires_at)}
+                            </time>
+                            "."
+                        </div>
+                        <ArticleBody article=article />
+                    }
+                        .into_any()
+                }
+                Some(Err(error)) => {
+                    #[cfg(feature = "ssr")]
+                    {
+                        let response = expect_context::<leptos_axum::ResponseOptions>();
+                        response.set_status(http::StatusCode::NOT_FOUND);
+                    }
+                    view! {
+                        <Title text=format!("Preview unavailable - {}", site::TITLE) />
+                        <div class="max-w-4xl mx-auto px-6 sm:px-8 lg:px-12 py-16 text-center">
+                            <h1 class="text-2xl font-bold text-gray-900 mb-4">
+                                "Preview Unavailable"
+                            </h1>
+                            <p class="text-gray-600 mb-2">{error}</p>
+                            <p class="text-gray-600 mb-8">
+                                "Ask the author for a new preview link."
+                            </p>
+                            <A href="/blog">
+                                <span class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors">
+                                    "Back to Blog"
+                                </span>
+                            </A>
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => ().into_any(),
+            }}
+        </Suspense>
+    }
+}
@@ -1,9 +1,16 @@
 //pub mod blog_detail;
-pub mod blog_content;
 pub mod blog_detail;
 //pub mod blog_editor;
 pub mod blog_list;
+pub mod blog_preview;
 pub mod footer;
 pub mod latest_blog_posts;
 pub mod navigation;
+pub mod permanent_redirect;
+pub mod post_metadata;
+pub mod related_posts;
+pub mod search_palette;
+pub mod series_parts;
+pub mod table_of_contents;
+pub mod tag_chips;
 pub mod tech_cards;
@@ -1,4 +1,5 @@
 #![allow(non_upper_case_globals)]
+use crate::components::search_palette::SearchPalette;
 use crate::constants::social::GITHUB_URL;
 use crate::constants::social::LINKEDIN_URL;
 use js_sys::wasm_bindgen;
@@ -27,6 +28,31 @@ pub fn Navigation() -> impl IntoView {
         set_mobile_menu_open.update(|open| *open = !*open);
     };
 
+    let search_open = RwSignal::new(false);
+
+    // Ctrl/Cmd+K anywhere, or "/" outside a text field, opens the search palette
+    #[cfg(not(feature = "ssr"))]
+    {
+        let handle = window_event_listener(leptos::ev::keydown, move |ev| {
+            let key = ev.key();
+            let shortcut = (key.eq_ignore_ascii_case("k") && (ev.ctrl_key() || ev.meta_key()))
+                || (key == "/" && !is_typing_target(&ev));
+            if shortcut {
+                ev.prevent_default();
+                search_open.set(true);
+            } else if key == "Escape" {
+                search_open.set(false);
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+
+    let open_search = move |ev: leptos::ev::MouseEvent| {
+        ev.prevent_default();
+        set_mobile_menu_open.set(false);
+        search_open.set(true);
+    };
+
     let handle_books_click = move |_| {
         // Use web_sys Location API directly
         if let Some(window) = web_sys::window() {
@@ -75,6 +101,16 @@ pub fn Navigation() -> impl IntoView {
                             >
                                 "About"
                             </A>
+                            <a
+                                href="/search"
+                                class="flex items-center text-gray-700 hover:text-blue-600 px-3 py-2 text-sm font-medium transition-colors duration-200"
+                                on:click=open_search
+                            >
+                                "Search"
+                                <kbd class="ml-2 px-1.5 py-0.5 text-xs text-gray-400 border border-gray-300 rounded">
+                                    "Ctrl K"
+                                </kbd>
+                            </a>
 
                             // Social Links
                             <div class="flex items-center space-x-4">
@@ -168,9 +204,30 @@ pub fn Navigation() -> impl IntoView {
                         >
                             "About"
                         </A>
+                        <a
+                            href="/search"
+                            class="text-gray-700 hover:text-blue-600 block px-3 py-2 text-base font-medium transition-colors duration-200"
+                            on:click=open_search
+                        >
+                            "Search"
+                        </a>
                     </div>
                 </div>
             </nav>
+            <SearchPalette open=search_open />
         </header>
     }
 }
+
+/// Whether a key press is headed for a text field, where "/" must stay a plain character.
+#[cfg(not(feature = "ssr"))]
+fn is_typing_target(ev: &leptos::ev::KeyboardEvent) -> bool {
+    use leptos::wasm_bindgen::JsCast;
+
+    ev.target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || element.is_content_editable()
+        })
+}
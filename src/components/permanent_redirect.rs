@@ -0,0 +1,35 @@
+use leptos::prelude::*;
+
+/// Redirects to `path` with `301 Moved Permanently` during SSR and replaces the
+/// current history entry when reached through client-side navigation.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn PermanentRedirect(path: String) -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    {
+        use http::{header::LOCATION, HeaderValue, StatusCode};
+
+        let response = expect_context::<leptos_axum::ResponseOptions>();
+        response.set_status(StatusCode::MOVED_PERMANENTLY);
+        if let Ok(location) = HeaderValue::from_str(&path) {
+            response.insert_header(LOCATION, location);
+        }
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        use leptos_router::{hooks::use_navigate, NavigateOptions};
+
+        let navigate = use_navigate();
+        request_animation_frame(move || {
+            navigate(
+                &path,
+                NavigateOptions {
+                    replace: true,
+                    ..NavigateOptions::default()
+                },
+            );
+        });
+    }
+}
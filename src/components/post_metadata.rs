@@ -0,0 +1,93 @@
+use leptos::prelude::*;
+use leptos_meta::{Link, Meta, Script, Title};
+
+use crate::constants::site;
+use crate::models::blog::BlogPost;
+use crate::utils::url::absolute_url;
+
+/// Search engine and link preview metadata for a single post: canonical link,
+/// Open Graph and Twitter Card tags and a schema.org `BlogPosting` JSON-LD block.
+///
+/// Rendered inside the blocking post resource, so all of it is part of the SSR
+/// response that crawlers and unfurlers see.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn PostMetadata(post: BlogPost, description: String, site_url: String) -> impl IntoView {
+    let title = format!("{} - {}", post.title, site::TITLE);
+    let url = absolute_url(&site_url, &post.path());
+    // Posts without a cover image share a generated card instead
+    let image = post.image_url.as_deref().map_or_else(
+        || absolute_url(&site_url, &post.og_image_path()),
+        |image| absolute_url(&site_url, image),
+    );
+    let published_time = post.created_at.to_rfc3339();
+    let modified_time = post.updated_at.to_rfc3339();
+    let json_ld = blog_posting_json_ld(&post, &description, &site_url, &url, &image);
+
+    view! {
+        <Title text=title />
+        <Meta name="description" content=description.clone() />
+        <Link rel="canonical" href=url.clone() />
+
+        <Meta property="og:type" content="article" />
+        <Meta property="og:site_name" content=site::TITLE />
+        <Meta property="og:locale" content="en_US" />
+        <Meta property="og:title" content=post.title.clone() />
+        <Meta property="og:description" content=description.clone() />
+        <Meta property="og:url" content=url />
+        <Meta property="og:image" content=image.clone() />
+        <Meta property="og:image:alt" content=format!("Cover image for {}", post.title) />
+        <Meta property="article:published_time" content=published_time />
+        <Meta property="article:modified_time" content=modified_time />
+        <Meta property="article:author" content=post.author.clone() />
+        {post
+            .tags
+            .iter()
+            .map(|tag| view! { <Meta property="article:tag" content=tag.clone() /> })
+            .collect_view()}
+
+        <Meta name="twitter:card" content="summary_large_image" />
+        <Meta name="twitter:title" content=post.title.clone() />
+        <Meta name="twitter:description" content=description />
+        <Meta name="twitter:image" content=image />
+
+        <Script type_="application/ld+json">{json_ld}</Script>
+    }
+}
+
+/// Builds the schema.org `BlogPosting` document for `post`.
+fn blog_posting_json_ld(
+    post: &BlogPost,
+    description: &str,
+    site_url: &str,
+    url: &str,
+    image: &str,
+) -> String {
+    let document = serde_json::json!({
+        "@context": "https://schema.org",
+        "@type": "BlogPosting",
+        "headline": post.title,
+        "image": image,
+        "description": description,
+        "url": url,
+        "mainEntityOfPage": { "@type": "WebPage", "@id": url },
+        "datePublished": post.created_at.to_rfc3339(),
+        "dateModified": post.updated_at.to_rfc3339(),
+        "author": { "@type": "Person", "name": post.author },
+        "publisher": {
+            "@type": "Person",
+            "name": site::AUTHOR,
+            "url": site_url,
+        },
+        "inLanguage": site::LANGUAGE,
+        "keywords": post.tags.join(", "),
+    });
+    // The script body is raw text: escape the characters that could end the
+    // element early or be mangled by HTML escaping. JSON parsers read them back unchanged.
+    document
+        .to_string()
+        .replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
@@ -0,0 +1,44 @@
+use leptos::prelude::*;
+
+use crate::api::blogs::get_related_posts_server;
+use crate::components::blog_list::BlogPostCard;
+
+/// "Keep reading" section at the bottom of a post. Loads after the post itself and
+/// renders nothing when there is nothing to recommend or loading fails.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn RelatedPosts(post_id: String) -> impl IntoView {
+    let related_resource = Resource::new(move || post_id.clone(), get_related_posts_server);
+
+    view! {
+        <Suspense fallback=|| ()>
+            {move || {
+                related_resource
+                    .get()
+                    .and_then(Result::ok)
+                    .filter(|posts| !posts.is_empty())
+                    .map(|posts| {
+                        view! {
+                            <section
+                                class="max-w-7xl mx-auto px-6 sm:px-8 lg:px-12 pb-16"
+                                aria-labelledby="related-posts-heading"
+                            >
+                                <h2
+                                    id="related-posts-heading"
+                                    class="text-2xl font-bold text-gray-900 mb-8"
+                                >
+                                    "Keep reading"
+                                </h2>
+                                <div class="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
+                                    {posts
+                                        .into_iter()
+                                        .map(|post| view! { <BlogPostCard post=post /> })
+                                        .collect_view()}
+                                </div>
+                            </section>
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,285 @@
+use std::sync::Arc;
+
+use leptos::logging;
+use leptos::prelude::*;
+use leptos_router::hooks::use_navigate;
+use leptos_router::NavigateOptions;
+
+use crate::models::search::{search_path, ClientSearchEntry, ClientSearchIndex};
+
+/// Results shown at once; the search page lists the rest.
+const MAX_PALETTE_RESULTS: usize = 8;
+
+#[derive(Debug, Clone)]
+enum IndexState {
+    NotLoaded,
+    Loading,
+    Ready(Arc<ClientSearchIndex>),
+    Failed,
+}
+
+/// Search-as-you-type dialog over the prebuilt index at `/search-index.json`. The index
+/// is downloaded the first time the palette opens and kept for the rest of the visit,
+/// so later searches are instant and work without a connection.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn SearchPalette(open: RwSignal<bool>) -> impl IntoView {
+    let index = RwSignal::new(IndexState::NotLoaded);
+    let query = RwSignal::new(String::new());
+    let selected = RwSignal::new(0_usize);
+    let input_ref = NodeRef::<leptos::html::Input>::new();
+    let navigate = use_navigate();
+
+    Effect::new(move || {
+        if !open.get() {
+            return;
+        }
+        request_animation_frame(move || {
+            if let Some(input) = input_ref.get_untracked() {
+                let _ = input.focus();
+                input.select();
+            }
+        });
+        let needs_loading = index
+            .with_untracked(|state| matches!(state, IndexState::NotLoaded | IndexState::Failed));
+        if needs_loading {
+            index.set(IndexState::Loading);
+            leptos::task::spawn_local(async move {
+                index.set(match fetch_search_index().await {
+                    Ok(loaded) => IndexState::Ready(Arc::new(loaded)),
+                    Err(e) => {
+                        logging::warn!("Failed to load search index: {e}");
+                        IndexState::Failed
+                    }
+                });
+            });
+        }
+    });
+
+    let results = Memo::new(move |_| {
+        index.with(|state| match state {
+            IndexState::Ready(index) => query.with(|query| {
+                index
+                    .search(query, MAX_PALETTE_RESULTS)
+                    .into_iter()
+                    .cloned()
+                    .collect::<Vec<ClientSearchEntry>>()
+            }),
+            _ => Vec::new(),
+        })
+    });
+
+    let go_to = move |path: String| {
+        open.set(false);
+        navigate(&path, NavigateOptions::default());
+    };
+    let on_keydown = {
+        let go_to = go_to.clone();
+        move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+            "ArrowDown" => {
+                ev.prevent_default();
+                let last = results.with(Vec::len).saturating_sub(1);
+                selected.update(|selected| *selected = (*selected + 1).min(last));
+            }
+            "ArrowUp" => {
+                ev.prevent_default();
+                selected.update(|selected| *selected = selected.saturating_sub(1));
+            }
+            "Enter" => {
+                ev.prevent_default();
+                let path = results
+                    .with(|results| results.get(selected.get()).map(|entry| entry.path.clone()))
+                    .or_else(|| {
+                        let query = query.get();
+                        (!query.trim().is_empty()).then(|| search_path(&query))
+                    });
+                if let Some(path) = path {
+                    go_to(path);
+                }
+            }
+            "Escape" => open.set(false),
+            _ => {}
+        }
+    };
+
+    view! {
+        <div
+            class="fixed inset-0 z-50 bg-gray-900 bg-opacity-50 px-4 pt-24"
+            class:hidden=move || !open.get()
+            on:click=move |_| open.set(false)
+        >
+            <div
+                class="max-w-xl mx-auto bg-white rounded-xl shadow-2xl overflow-hidden"
+                role="dialog"
+                aria-modal="true"
+                aria-label="Search"
+                on:click=|ev| ev.stop_propagation()
+            >
+                <div class="flex items-center border-b border-gray-200 px-4">
+                    <svg
+                        class="h-5 w-5 text-gray-400"
+                        fill="none"
+                        viewBox="0 0 24 24"
+                        stroke="currentColor"
+                        aria-hidden="true"
+                    >
+                        <path
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                            stroke-width="2"
+                            d="M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z"
+                        />
+                    </svg>
+                    <input
+                        node_ref=input_ref
+                        type="search"
+                        placeholder="Search articles and books..."
+                        aria-label="Search articles and books"
+                        class="flex-1 px-3 py-4 text-base text-gray-900 focus:outline-none"
+                        prop:value=move || query.get()
+                        on:input=move |ev| {
+                            query.set(event_target_value(&ev));
+                            selected.set(0);
+                        }
+                        on:keydown=on_keydown
+                    />
+                    <kbd class="hidden sm:inline-block px-2 py-0.5 text-xs text-gray-500 border border-gray-300 rounded">
+                        "Esc"
+                    </kbd>
+                </div>
+
+                <div class="max-h-96 overflow-y-auto">
+                    {move || {
+                        let has_query = query.with(|query| !query.trim().is_empty());
+                        index
+                            .with(|state| match state {
+                                IndexState::NotLoaded | IndexState::Loading => {
+                                    Some("Loading search index...".to_string())
+                                }
+                                IndexState::Failed if has_query => {
+                                    Some(
+                                        "Instant search is unavailable. Press Enter to search the full text."
+                                            .to_string(),
+                                    )
+                                }
+                                IndexState::Failed => {
+                                    Some("Instant search is unavailable right now.".to_string())
+                                }
+                                IndexState::Ready(_) if !has_query => {
+                                    Some("Type to search articles and books.".to_string())
+                                }
+                                IndexState::Ready(_) if results.with(Vec::is_empty) => {
+                                    Some(
+                                        "No quick matches. Press Enter to search the full text."
+                                            .to_string(),
+                                    )
+                                }
+                                IndexState::Ready(_) => None,
+                            })
+                            .map(|message| {
+                                view! { <p class="px-4 py-6 text-sm text-gray-500">{message}</p> }
+                            })
+                    }}
+                    <ul>
+                        {
+                            let go_to = go_to.clone();
+                            move || {
+                                results
+                                    .get()
+                                    .into_iter()
+                                    .enumerate()
+                                    .map(|(position, entry)| {
+                                        let go_to = go_to.clone();
+                                        let path = entry.path.clone();
+                                        view! {
+                                            <li>
+                                                <a
+                                                    href=entry.path.clone()
+                                                    class=move || {
+                                                        if selected.get() == position {
+                                                            "block px-4 py-3 bg-blue-50"
+                                                        } else {
+                                                            "block px-4 py-3 hover:bg-gray-50"
+                                                        }
+                                                    }
+                                                    on:mouseenter=move |_| selected.set(position)
+                                                    on:click=move |ev| {
+                                                        ev.prevent_default();
+                                                        go_to(path.clone());
+                                                    }
+                                                >
+                                                    <span class="block text-sm font-medium text-gray-900">
+                                                        {entry.title}
+                                                    </span>
+                                                    <span class="block text-xs text-gray-500">
+                                                        {format!("{} · {}", entry.kind.label(), entry.detail)}
+                                                    </span>
+                                                </a>
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()
+                            }
+                        }
+                    </ul>
+                </div>
+
+                <div class="flex items-center justify-between border-t border-gray-200 px-4 py-2 text-xs text-gray-500">
+                    <span>"↑ ↓ to choose, Enter to open"</span>
+                    {move || {
+                        let query = query.get();
+                        (!query.trim().is_empty())
+                            .then(|| {
+                                let go_to = go_to.clone();
+                                let path = search_path(&query);
+                                view! {
+                                    <a
+                                        href=path.clone()
+                                        class="font-medium text-blue-600 hover:text-blue-800"
+                                        on:click=move |ev| {
+                                            ev.prevent_default();
+                                            go_to(path.clone());
+                                        }
+                                    >
+                                        "All results"
+                                    </a>
+                                }
+                            })
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(feature = "hydrate")]
+async fn fetch_search_index() -> Result<ClientSearchIndex, String> {
+    use crate::models::search::CLIENT_INDEX_VERSION;
+
+    let response = gloo_net::http::Request::get("/search-index.json")
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!(
+            "Search index request failed with status {}",
+            response.status()
+        ));
+    }
+    let index: ClientSearchIndex = response.json().await.map_err(|e| e.to_string())?;
+    // An index from a newer deployment may not match what this bundle understands
+    if index.version != CLIENT_INDEX_VERSION {
+        return Err(format!(
+            "Unsupported search index version {}",
+            index.version
+        ));
+    }
+    Ok(index)
+}
+
+#[cfg(not(feature = "hydrate"))]
+#[allow(clippy::unused_async)]
+async fn fetch_search_index() -> Result<ClientSearchIndex, String> {
+    Err("The search index is only loaded in the browser".to_string())
+}
@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::models::series::{SeriesNavigation, SeriesPart};
+
+/// "Part 2 of 5" box for posts in a series: every part in reading order with the
+/// current one highlighted, followed by links to the previous and next part.
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn SeriesParts(series: SeriesNavigation) -> impl IntoView {
+    let previous = series.previous().cloned();
+    let next = series.next().cloned();
+    let current = series.current;
+
+    view! {
+        <nav
+            class="mb-12 rounded-xl border border-blue-100 bg-blue-50 p-6"
+            aria-label="Series navigation"
+        >
+            <p class="text-sm font-semibold uppercase tracking-wide text-blue-600">
+                {format!("Part {} of {}", series.current + 1, series.parts.len())}
+            </p>
+            <h2 class="mt-1 text-xl font-bold text-gray-900">
+                <A href=series.path.clone() attr:class="hover:text-blue-700 transition-colors">
+                    {series.title.clone()}
+                </A>
+            </h2>
+
+            <ol class="mt-4 space-y-2 list-decimal pl-6 text-gray-700">
+                {series
+                    .parts
+                    .clone()
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, part)| {
+                        if index == current {
+                            view! {
+                                <li class="font-semibold text-gray-900" aria-current="page">
+                                    {part.title}
+                                </li>
+                            }
+                                .into_any()
+                        } else {
+                            view! {
+                                <li>
+                                    <A
+                                        href=part.path
+                                        attr:class="text-blue-600 hover:text-blue-800 transition-colors"
+                                    >
+                                        {part.title}
+                                    </A>
+                                </li>
+                            }
+                                .into_any()
+                        }
+                    })
+                    .collect_view()}
+            </ol>
+
+            <div class="mt-6 flex justify-between gap-4 text-sm font-medium">
+                {series_link(previous, "← Previous")}
+                {series_link(next, "Next →")}
+            </div>
+        </nav>
+    }
+}
+
+fn series_link(part: Option<SeriesPart>, label: &'static str) -> AnyView {
+    match part {
+        Some(part) => view! {
+            <A
+                href=part.path
+                attr:class="text-blue-600 hover:text-blue-800 transition-colors"
+                attr:title=part.title
+            >
+                {label}
+            </A>
+        }
+        .into_any(),
+        // Keeps "Next" on the right when there is no previous part
+        None => view! { <span></span> }.into_any(),
+    }
+}
@@ -0,0 +1,109 @@
+use leptos::prelude::*;
+
+use crate::rendering::TocEntry;
+
+/// How far below the top of the viewport a heading counts as the current section,
+/// roughly the height of the sticky navigation bar.
+#[cfg(not(feature = "ssr"))]
+const SCROLL_SPY_OFFSET: f64 = 120.0;
+
+/// Sticky table of contents that highlights the section currently being read.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn TableOfContents(entries: Vec<TocEntry>) -> impl IntoView {
+    let active_id = RwSignal::new(None::<String>);
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let ids = flatten_ids(&entries);
+        let update_active = move || {
+            let current = current_section(&ids);
+            if active_id.with_untracked(|active| *active != current) {
+                active_id.set(current);
+            }
+        };
+
+        request_animation_frame({
+            let update_active = update_active.clone();
+            move || update_active()
+        });
+        let handle = window_event_listener(leptos::ev::scroll, move |_| update_active());
+        on_cleanup(move || handle.remove());
+    }
+
+    view! {
+        <nav
+            aria-label="Table of contents"
+            class="sticky top-24 max-h-[calc(100vh-8rem)] overflow-y-auto"
+        >
+            <p class="text-sm font-semibold uppercase tracking-wide text-gray-900 mb-4">
+                "On this page"
+            </p>
+            {toc_list(entries, active_id)}
+        </nav>
+    }
+}
+
+fn toc_list(entries: Vec<TocEntry>, active_id: RwSignal<Option<String>>) -> AnyView {
+    view! {
+        <ul class="space-y-2 text-sm">
+            {entries
+                .into_iter()
+                .map(|entry| {
+                    let id = entry.id.clone();
+                    let link_class = move || {
+                        if active_id.with(|active| active.as_deref() == Some(id.as_str())) {
+                            "block border-l-2 border-blue-600 pl-3 font-medium text-blue-700"
+                        } else {
+                            "block border-l-2 border-transparent pl-3 text-gray-600 hover:text-gray-900 hover:border-gray-300 transition-colors"
+                        }
+                    };
+                    view! {
+                        <li>
+                            <a href=format!("#{}", entry.id) class=link_class>
+                                {entry.text}
+                            </a>
+                            {(!entry.children.is_empty())
+                                .then(|| {
+                                    view! {
+                                        <div class="mt-2 ml-3">
+                                            {toc_list(entry.children, active_id)}
+                                        </div>
+                                    }
+                                })}
+                        </li>
+                    }
+                })
+                .collect_view()}
+        </ul>
+    }
+    .into_any()
+}
+
+#[cfg(not(feature = "ssr"))]
+fn flatten_ids(entries: &[TocEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .flat_map(|entry| std::iter::once(entry.id.clone()).chain(flatten_ids(&entry.children)))
+        .collect()
+}
+
+/// The last heading that has scrolled past the top of the viewport.
+#[cfg(not(feature = "ssr"))]
+fn current_section(ids: &[String]) -> Option<String> {
+    use leptos::wasm_bindgen::JsCast;
+    use leptos::web_sys::HtmlElement;
+
+    let document = document();
+    let position = window().scroll_y().unwrap_or_default() + SCROLL_SPY_OFFSET;
+
+    ids.iter()
+        .take_while(|id| {
+            document
+                .get_element_by_id(id)
+                .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+                .is_some_and(|heading| f64::from(heading.offset_top()) <= position)
+        })
+        .last()
+        .cloned()
+}
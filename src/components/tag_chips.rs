@@ -0,0 +1,49 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::models::blog::tag_path;
+
+/// Tags as pill links to their landing pages. With `limit`, only the first `limit` tags
+/// are shown, followed by how many were left out. `large` is for the post page header.
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn TagChips(
+    tags: Vec<String>,
+    #[prop(optional)] limit: Option<usize>,
+    #[prop(optional)] large: bool,
+) -> impl IntoView {
+    let size = if large {
+        "px-4 py-2 text-sm"
+    } else {
+        "px-2.5 py-0.5 text-xs"
+    };
+    let hidden = limit.map_or(0, |limit| tags.len().saturating_sub(limit));
+    let shown = tags.len() - hidden;
+
+    view! {
+        {tags
+            .into_iter()
+            .take(shown)
+            .map(|tag| {
+                view! {
+                    <A
+                        href=tag_path(&tag)
+                        attr:class=format!(
+                            "inline-flex items-center {size} rounded-full font-medium bg-blue-100 text-blue-800 hover:bg-blue-200 transition-colors",
+                        )
+                    >
+                        {tag}
+                    </A>
+                }
+            })
+            .collect_view()}
+        {(hidden > 0)
+            .then(|| {
+                view! {
+                    <span class=format!(
+                        "inline-flex items-center {size} rounded-full font-medium bg-gray-100 text-gray-600",
+                    )>{format!("+{hidden} more")}</span>
+                }
+            })}
+    }
+}
@@ -5,3 +5,18 @@ pub mod social {
     /// `LinkedIn` profile URL  
     pub const LINKEDIN_URL: &str = "https://www.linkedin.com/in/mikko-lepp%C3%A4nen-05bb621a/";
 }
+
+pub mod site {
+    /// Site name shown in titles and feeds
+    pub const TITLE: &str = "Mike's Dev Blog";
+
+    /// Short description of the site for feeds and search engines
+    pub const DESCRIPTION: &str =
+        "Thoughts on modern software development, architecture patterns and emerging technologies.";
+
+    /// Default author of all posts
+    pub const AUTHOR: &str = "Mikko Leppänen";
+
+    /// Content language
+    pub const LANGUAGE: &str = "en";
+}
@@ -0,0 +1,313 @@
+use atom_syndication::{
+    CategoryBuilder, ContentBuilder, EntryBuilder, FeedBuilder, LinkBuilder, PersonBuilder,
+};
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use leptos::logging;
+use rss::{
+    extension::dublincore::DublinCoreExtensionBuilder, CategoryBuilder as RssCategoryBuilder,
+    ChannelBuilder, GuidBuilder, ItemBuilder,
+};
+
+use crate::{
+    constants::site,
+    handlers::{conditional_response, error_response, site_url},
+    models::{
+        blog::{tag_path, BlogPost},
+        json_feed::{JsonFeed, JsonFeedAuthor, JsonFeedItem, JSON_FEED_VERSION},
+    },
+    rendering::render_markdown,
+    services::cosmos::blog_repository::get_blog_service,
+    utils::url::absolute_url,
+};
+
+/// Number of most recent posts included in every feed.
+const FEED_ITEM_LIMIT: i32 = 50;
+
+const RSS_CONTENT_TYPE: &str = "application/rss+xml; charset=utf-8";
+const ATOM_CONTENT_TYPE: &str = "application/atom+xml; charset=utf-8";
+const JSON_FEED_CONTENT_TYPE: &str = "application/feed+json; charset=utf-8";
+
+/// Metadata of the feed being generated: either the whole blog or a single tag.
+struct FeedInfo {
+    title: String,
+    description: String,
+    /// Site-relative path of the HTML page the feed mirrors
+    page_path: String,
+    /// Site-relative path of the feed itself
+    self_path: String,
+    /// Public base URL that every link in the feed is made absolute against
+    base_url: &'static str,
+}
+
+/// `GET /feed.xml`
+pub async fn rss_feed(headers: HeaderMap) -> Response {
+    match load_latest_posts().await {
+        Ok(posts) => {
+            let info = match blog_feed_info("/feed.xml") {
+                Ok(info) => info,
+                Err(response) => return response,
+            };
+            conditional_response(
+                &headers,
+                RSS_CONTENT_TYPE,
+                render_rss(&info, &posts),
+                last_modified(&posts),
+            )
+        }
+        Err(response) => response,
+    }
+}
+
+/// `GET /atom.xml`
+pub async fn atom_feed(headers: HeaderMap) -> Response {
+    match load_latest_posts().await {
+        Ok(posts) => {
+            let info = match blog_feed_info("/atom.xml") {
+                Ok(info) => info,
+                Err(response) => return response,
+            };
+            conditional_response(
+                &headers,
+                ATOM_CONTENT_TYPE,
+                render_atom(&info, &posts),
+                last_modified(&posts),
+            )
+        }
+        Err(response) => response,
+    }
+}
+
+/// `GET /feed.json`
+pub async fn json_feed(headers: HeaderMap) -> Response {
+    match load_latest_posts().await {
+        Ok(posts) => {
+            let info = match blog_feed_info("/feed.json") {
+                Ok(info) => info,
+                Err(response) => return response,
+            };
+            match serde_json::to_string_pretty(&build_json_feed(&info, &posts)) {
+                Ok(body) => conditional_response(
+                    &headers,
+                    JSON_FEED_CONTENT_TYPE,
+                    body,
+                    last_modified(&posts),
+                ),
+                Err(e) => {
+                    logging::error!("Failed to serialize JSON feed: {:?}", e);
+                    error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to build feed")
+                }
+            }
+        }
+        Err(response) => response,
+    }
+}
+
+/// `GET /blog/tags/{tag}/feed.xml`
+pub async fn tag_rss_feed(Path(tag_slug): Path<String>, headers: HeaderMap) -> Response {
+    let base_url = match site_url() {
+        Ok(base_url) => base_url,
+        Err(response) => return response,
+    };
+    let Ok(blog_service) = get_blog_service() else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Blog storage is unavailable",
+        );
+    };
+
+    let spellings = match blog_service.find_tag_spellings(&tag_slug).await {
+        Ok(spellings) => spellings,
+        Err(e) => {
+            logging::error!("Failed to resolve tag '{}' for feed: {:?}", tag_slug, e);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load tag");
+        }
+    };
+    let Some(tag) = spellings.first().cloned() else {
+        return error_response(StatusCode::NOT_FOUND, "Tag not found");
+    };
+
+    let mut posts = match blog_service.list_published_posts_by_tags(&spellings).await {
+        Ok(posts) => posts,
+        Err(e) => {
+            logging::error!("Failed to fetch posts for tag feed '{}': {:?}", tag, e);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load posts");
+        }
+    };
+    posts.truncate(usize::try_from(FEED_ITEM_LIMIT).unwrap_or_default());
+
+    let info = FeedInfo {
+        title: format!("{} - {tag}", site::TITLE),
+        description: format!("Posts tagged \"{tag}\" on {}", site::TITLE),
+        page_path: tag_path(&tag),
+        self_path: format!("{}/feed.xml", tag_path(&tag)),
+        base_url,
+    };
+
+    conditional_response(
+        &headers,
+        RSS_CONTENT_TYPE,
+        render_rss(&info, &posts),
+        last_modified(&posts),
+    )
+}
+
+pub(crate) async fn load_latest_posts() -> Result<Vec<BlogPost>, Response> {
+    let blog_service = get_blog_service().map_err(|e| {
+        logging::error!("Blog service unavailable for feed: {}", e);
+        error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Blog storage is unavailable",
+        )
+    })?;
+
+    blog_service
+        .list_latest_blog_posts(FEED_ITEM_LIMIT)
+        .await
+        .map_err(|e| {
+            logging::error!("Failed to fetch posts for feed: {:?}", e);
+            error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load posts")
+        })
+}
+
+#[allow(clippy::result_large_err)]
+fn blog_feed_info(self_path: &str) -> Result<FeedInfo, Response> {
+    Ok(FeedInfo {
+        title: site::TITLE.to_string(),
+        description: site::DESCRIPTION.to_string(),
+        page_path: "/blog".to_string(),
+        self_path: self_path.to_string(),
+        base_url: site_url()?,
+    })
+}
+
+pub(crate) fn last_modified(posts: &[BlogPost]) -> Option<DateTime<Utc>> {
+    posts.iter().map(|post| post.updated_at).max()
+}
+
+fn render_rss(info: &FeedInfo, posts: &[BlogPost]) -> String {
+    let items: Vec<rss::Item> = posts
+        .iter()
+        .map(|post| {
+            let link = absolute_url(info.base_url, &post.path());
+            ItemBuilder::default()
+                .title(Some(post.title.clone()))
+                .link(Some(link.clone()))
+                .guid(Some(
+                    GuidBuilder::default().value(link).permalink(true).build(),
+                ))
+                .pub_date(Some(post.created_at.to_rfc2822()))
+                .description(Some(render_markdown(&post.content).html))
+                .categories(
+                    post.tags
+                        .iter()
+                        .map(|tag| RssCategoryBuilder::default().name(tag.clone()).build())
+                        .collect::<Vec<_>>(),
+                )
+                .dublin_core_ext(Some(
+                    DublinCoreExtensionBuilder::default()
+                        .creators(vec![post.author.clone()])
+                        .build(),
+                ))
+                .build()
+        })
+        .collect();
+
+    ChannelBuilder::default()
+        .title(info.title.clone())
+        .link(absolute_url(info.base_url, &info.page_path))
+        .description(info.description.clone())
+        .language(Some(site::LANGUAGE.to_string()))
+        .last_build_date(last_modified(posts).map(|date| date.to_rfc2822()))
+        .items(items)
+        .build()
+        .to_string()
+}
+
+fn render_atom(info: &FeedInfo, posts: &[BlogPost]) -> String {
+    let entries: Vec<atom_syndication::Entry> = posts
+        .iter()
+        .map(|post| {
+            let link = absolute_url(info.base_url, &post.path());
+            EntryBuilder::default()
+                .title(post.title.clone())
+                .id(link.clone())
+                .updated(post.updated_at.fixed_offset())
+                .published(Some(post.created_at.fixed_offset()))
+                .authors(vec![PersonBuilder::default()
+                    .name(post.author.clone())
+                    .build()])
+                .links(vec![LinkBuilder::default()
+                    .href(link)
+                    .rel("alternate")
+                    .mime_type(Some("text/html".to_string()))
+                    .build()])
+                .categories(
+                    post.tags
+                        .iter()
+                        .map(|tag| CategoryBuilder::default().term(tag.clone()).build())
+                        .collect::<Vec<_>>(),
+                )
+                .content(Some(
+                    ContentBuilder::default()
+                        .content_type(Some("html".to_string()))
+                        .value(Some(render_markdown(&post.content).html))
+                        .build(),
+                ))
+                .build()
+        })
+        .collect();
+
+    FeedBuilder::default()
+        .title(info.title.clone())
+        .subtitle(Some(info.description.clone().into()))
+        .id(absolute_url(info.base_url, &info.page_path))
+        .updated(last_modified(posts).unwrap_or_else(Utc::now).fixed_offset())
+        .authors(vec![PersonBuilder::default().name(site::AUTHOR).build()])
+        .links(vec![
+            LinkBuilder::default()
+                .href(absolute_url(info.base_url, &info.page_path))
+                .rel("alternate")
+                .mime_type(Some("text/html".to_string()))
+                .build(),
+            LinkBuilder::default()
+                .href(absolute_url(info.base_url, &info.self_path))
+                .rel("self")
+                .mime_type(Some("application/atom+xml".to_string()))
+                .build(),
+        ])
+        .lang(Some(site::LANGUAGE.to_string()))
+        .entries(entries)
+        .build()
+        .to_string()
+}
+
+fn build_json_feed(info: &FeedInfo, posts: &[BlogPost]) -> JsonFeed {
+    JsonFeed {
+        version: JSON_FEED_VERSION.to_string(),
+        title: info.title.clone(),
+        home_page_url: Some(absolute_url(info.base_url, &info.page_path)),
+        feed_url: Some(absolute_url(info.base_url, &info.self_path)),
+        description: Some(info.description.clone()),
+        icon: Some(absolute_url(info.base_url, "/images/dev-logo.png")),
+        language: Some(site::LANGUAGE.to_string()),
+        authors: vec![JsonFeedAuthor::named(site::AUTHOR)],
+        items: posts
+            .iter()
+            .map(|post| {
+                let content = render_markdown(&post.content);
+                let summary = post.stats().excerpt;
+                JsonFeedItem::from_post(
+                    post,
+                    absolute_url(info.base_url, &post.path()),
+                    content.html,
+                    Some(summary).filter(|summary| !summary.is_empty()),
+                )
+            })
+            .collect(),
+    }
+}
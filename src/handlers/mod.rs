@@ -0,0 +1,109 @@
+//! Plain Axum handlers for machine-readable endpoints that live outside the Leptos router.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use axum::{
+    body::Bytes,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use leptos::logging;
+
+use crate::services::config::try_get_config;
+
+pub mod feeds;
+pub mod og_image;
+pub mod search_index;
+pub mod sitemap;
+
+/// How long clients and proxies may cache generated documents.
+const CACHE_CONTROL: &str = "public, max-age=900";
+
+/// Builds a cacheable response with `ETag` and `Last-Modified` headers and answers
+/// `If-None-Match` / `If-Modified-Since` requests with `304 Not Modified`.
+pub fn conditional_response(
+    request_headers: &HeaderMap,
+    content_type: &'static str,
+    body: impl Into<Bytes>,
+    last_modified: Option<DateTime<Utc>>,
+) -> Response {
+    let body = body.into();
+    let etag = {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        format!("\"{:016x}\"", hasher.finish())
+    };
+    let last_modified_header =
+        last_modified.map(|date| date.format("%a, %d %b %Y %H:%M:%S GMT").to_string());
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(CACHE_CONTROL),
+    );
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        headers.insert(header::ETAG, value);
+    }
+    if let Some(value) = last_modified_header
+        .as_deref()
+        .and_then(|date| HeaderValue::from_str(date).ok())
+    {
+        headers.insert(header::LAST_MODIFIED, value);
+    }
+
+    if is_not_modified(request_headers, &etag, last_modified) {
+        return (StatusCode::NOT_MODIFIED, headers).into_response();
+    }
+
+    (StatusCode::OK, headers, body).into_response()
+}
+
+fn is_not_modified(
+    request_headers: &HeaderMap,
+    etag: &str,
+    last_modified: Option<DateTime<Utc>>,
+) -> bool {
+    // If-None-Match takes precedence over If-Modified-Since (RFC 9110, 13.2.2)
+    if let Some(if_none_match) = request_headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+    {
+        return if_none_match
+            .split(',')
+            .map(str::trim)
+            .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag);
+    }
+
+    let if_modified_since = request_headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| DateTime::parse_from_rfc2822(value).ok());
+
+    match (if_modified_since, last_modified) {
+        // HTTP dates have second precision
+        (Some(since), Some(modified)) => modified.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+/// Plain-text error response used when a document cannot be generated.
+#[must_use]
+pub fn error_response(status: StatusCode, message: &str) -> Response {
+    (status, message.to_string()).into_response()
+}
+
+/// Public base URL of the site for absolute links, or a `503` when the configuration is unavailable.
+#[allow(clippy::result_large_err)]
+pub(crate) fn site_url() -> Result<&'static str, Response> {
+    try_get_config()
+        .map(|config| config.site.base_url.as_str())
+        .map_err(|e| {
+            logging::error!("Site configuration unavailable: {}", e);
+            error_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Site configuration is unavailable",
+            )
+        })
+}
@@ -0,0 +1,301 @@
+//! Social card images (`/og/{slug}.png`) for posts without a cover image.
+//!
+//! Cards are drawn in-process with an embedded font and cached on disk, keyed by the
+//! post fields they show, so an edit to the title or tags produces a fresh image.
+
+use std::{
+    fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    io::Cursor,
+    path::Path as FsPath,
+};
+
+use ab_glyph::{Font, FontRef, PxScale};
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+use chrono::Utc;
+use color_eyre::{eyre::WrapErr, Result};
+use image::{
+    imageops::{self, FilterType},
+    ImageFormat, Rgba, RgbaImage,
+};
+use imageproc::{
+    drawing::{draw_filled_rect_mut, draw_text_mut, text_size},
+    rect::Rect,
+};
+use leptos::logging;
+
+use crate::{
+    constants::site,
+    handlers::{conditional_response, error_response},
+    models::blog::BlogPost,
+    services::{config::try_get_config, cosmos::blog_repository::get_blog_service},
+    utils::date_formatter::format_date_readable,
+};
+
+const WIDTH: u32 = 1200;
+const HEIGHT: u32 = 630;
+const PADDING: i32 = 80;
+const LOGO_SIZE: u32 = 88;
+
+/// Bump when the card layout changes so cached images are regenerated.
+const LAYOUT_VERSION: u32 = 1;
+
+const FONT_BOLD: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+const FONT_REGULAR: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const LOGO: &[u8] = include_bytes!("../../public/images/dev-logo.png");
+
+// Brand gradient, same colors as the headings on the site (blue-600 to purple-600)
+const GRADIENT_START: [u8; 3] = [37, 99, 235];
+const GRADIENT_END: [u8; 3] = [147, 51, 234];
+const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const SOFT_WHITE: Rgba<u8> = Rgba([224, 231, 255, 255]);
+const TAG_TEXT: Rgba<u8> = Rgba([29, 78, 216, 255]);
+
+/// `GET /og/{slug}.png`
+pub async fn og_image(Path(file): Path<String>, headers: HeaderMap) -> Response {
+    let Some(slug) = file.strip_suffix(".png") else {
+        return error_response(StatusCode::NOT_FOUND, "Image not found");
+    };
+
+    let Ok(blog_service) = get_blog_service() else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Blog storage is unavailable",
+        );
+    };
+
+    let post = match blog_service.get_blog_post_by_slug(slug).await {
+        Ok(Some(post)) => Some(post),
+        // Posts stored before slugs existed are addressed by id
+        Ok(None) => blog_service.get_blog_post(slug).await.ok(),
+        Err(e) => {
+            logging::error!("Failed to load post '{}' for social card: {:?}", slug, e);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load post");
+        }
+    };
+    let Some(post) = post.filter(|post| post.is_reachable_at(Utc::now())) else {
+        return error_response(StatusCode::NOT_FOUND, "Image not found");
+    };
+
+    let cache_dir = try_get_config()
+        .ok()
+        .map(|config| config.server.og_image_cache_dir.clone());
+    let last_modified = post.updated_at;
+
+    let rendered =
+        tokio::task::spawn_blocking(move || load_or_render(&post, cache_dir.as_deref())).await;
+
+    match rendered {
+        Ok(Ok(png)) => conditional_response(&headers, "image/png", png, Some(last_modified)),
+        Ok(Err(e)) => {
+            logging::error!("Failed to render social card for '{}': {:?}", slug, e);
+            error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to render image")
+        }
+        Err(e) => {
+            logging::error!("Social card task for '{}' failed: {}", slug, e);
+            error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to render image")
+        }
+    }
+}
+
+/// Returns the cached card for `post`, rendering and storing it on a cache miss.
+/// Cache failures are logged and never fail the request.
+fn load_or_render(post: &BlogPost, cache_dir: Option<&FsPath>) -> Result<Vec<u8>> {
+    let cache_path = cache_dir.map(|dir| dir.join(cache_file_name(post)));
+
+    if let Some(png) = cache_path.as_ref().and_then(|path| fs::read(path).ok()) {
+        return Ok(png);
+    }
+
+    let png = render_card(post)?;
+
+    if let Some(path) = cache_path {
+        if let Err(e) = write_cache(&path, &png) {
+            logging::warn!("Failed to cache social card {}: {:?}", path.display(), e);
+        }
+    }
+
+    Ok(png)
+}
+
+fn cache_file_name(post: &BlogPost) -> String {
+    let mut hasher = DefaultHasher::new();
+    LAYOUT_VERSION.hash(&mut hasher);
+    post.id.hash(&mut hasher);
+    post.title.hash(&mut hasher);
+    post.author.hash(&mut hasher);
+    post.tags.hash(&mut hasher);
+    post.created_at.hash(&mut hasher);
+    format!("{:016x}.png", hasher.finish())
+}
+
+fn write_cache(path: &FsPath, png: &[u8]) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).wrap_err("Failed to create cache directory")?;
+    }
+    // Write then rename, so concurrent requests never read a half-written file
+    let temp_path = path.with_extension("png.tmp");
+    fs::write(&temp_path, png).wrap_err("Failed to write cache file")?;
+    fs::rename(&temp_path, path).wrap_err("Failed to move cache file into place")
+}
+
+/// Draws the 1200×630 card: logo and site name, title, tags, author and date.
+fn render_card(post: &BlogPost) -> Result<Vec<u8>> {
+    let bold = FontRef::try_from_slice(FONT_BOLD).wrap_err("Invalid embedded bold font")?;
+    let regular =
+        FontRef::try_from_slice(FONT_REGULAR).wrap_err("Invalid embedded regular font")?;
+    let max_text_width = WIDTH - 2 * PADDING.unsigned_abs();
+
+    let mut card = RgbaImage::from_fn(WIDTH, HEIGHT, |x, y| {
+        let (step, steps) = (x + y, WIDTH + HEIGHT);
+        Rgba([
+            lerp(GRADIENT_START[0], GRADIENT_END[0], step, steps),
+            lerp(GRADIENT_START[1], GRADIENT_END[1], step, steps),
+            lerp(GRADIENT_START[2], GRADIENT_END[2], step, steps),
+            255,
+        ])
+    });
+
+    // Header: logo and site name
+    let logo = image::load_from_memory(LOGO)
+        .wrap_err("Invalid embedded logo")?
+        .to_rgba8();
+    let logo = imageops::resize(&logo, LOGO_SIZE, LOGO_SIZE, FilterType::Lanczos3);
+    imageops::overlay(
+        &mut card,
+        &logo,
+        i64::from(PADDING),
+        i64::from(PADDING) - 20,
+    );
+    draw_text_mut(
+        &mut card,
+        WHITE,
+        PADDING + 112,
+        PADDING + 6,
+        PxScale::from(36.0),
+        &bold,
+        site::TITLE,
+    );
+
+    // Title, at most three lines
+    let title_scale = PxScale::from(64.0);
+    for (index, line) in wrap_text(&post.title, title_scale, &bold, max_text_width, 3)
+        .iter()
+        .enumerate()
+    {
+        let offset = i32::try_from(index).unwrap_or_default() * 78;
+        draw_text_mut(
+            &mut card,
+            WHITE,
+            PADDING,
+            210 + offset,
+            title_scale,
+            &bold,
+            line,
+        );
+    }
+
+    // Tags as chips, as many as fit on one row
+    let tag_scale = PxScale::from(24.0);
+    let tag_y = i32::try_from(HEIGHT).unwrap_or_default() - PADDING - 110;
+    let mut tag_x = PADDING;
+    for tag in &post.tags {
+        let (text_width, _) = text_size(tag_scale, &regular, tag);
+        let chip_width = text_width + 36;
+        let chip_end = i64::from(tag_x) + i64::from(chip_width);
+        if chip_end > i64::from(WIDTH) - i64::from(PADDING) {
+            break;
+        }
+        draw_filled_rect_mut(
+            &mut card,
+            Rect::at(tag_x, tag_y).of_size(chip_width, 44),
+            WHITE,
+        );
+        draw_text_mut(
+            &mut card,
+            TAG_TEXT,
+            tag_x + 18,
+            tag_y + 9,
+            tag_scale,
+            &regular,
+            tag,
+        );
+        tag_x += i32::try_from(chip_width).unwrap_or_default() + 12;
+    }
+
+    // Footer: author and publication date
+    draw_text_mut(
+        &mut card,
+        SOFT_WHITE,
+        PADDING,
+        i32::try_from(HEIGHT).unwrap_or_default() - PADDING - 30,
+        PxScale::from(30.0),
+        &regular,
+        &format!(
+            "{}  ·  {}",
+            post.author,
+            format_date_readable(post.created_at)
+        ),
+    );
+
+    let mut png = Vec::new();
+    card.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .wrap_err("Failed to encode social card")?;
+    Ok(png)
+}
+
+/// Linear interpolation between two color channels at `step / steps`.
+fn lerp(from: u8, to: u8, step: u32, steps: u32) -> u8 {
+    let step = step.min(steps);
+    let value = (u32::from(from) * (steps - step) + u32::from(to) * step) / steps.max(1);
+    u8::try_from(value).unwrap_or(u8::MAX)
+}
+
+/// Greedily wraps `text` into lines no wider than `max_width`. When the text needs
+/// more than `max_lines` lines the last one is cut and ends with an ellipsis.
+fn wrap_text(
+    text: &str,
+    scale: PxScale,
+    font: &impl Font,
+    max_width: u32,
+    max_lines: usize,
+) -> Vec<String> {
+    let fits = |candidate: &str| text_size(scale, font, candidate).0 <= max_width;
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{current} {word}")
+        };
+        if fits(&candidate) || current.is_empty() {
+            current = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    let truncated = lines.len() > max_lines;
+    lines.truncate(max_lines);
+
+    // Shorten the last line when text was dropped or a single word is too wide
+    if let Some(last) = lines.last_mut() {
+        if truncated || !fits(last) {
+            while !last.is_empty() && !fits(&format!("{last}…")) {
+                last.pop();
+            }
+            *last = format!("{}…", last.trim_end());
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,50 @@
+use axum::{
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+use leptos::logging;
+
+use crate::{
+    handlers::{conditional_response, error_response},
+    services::{
+        cosmos::{blog_repository::get_blog_service, book_repository::get_book_service},
+        search::client_index_json,
+    },
+};
+
+const JSON_CONTENT_TYPE: &str = "application/json; charset=utf-8";
+
+/// `GET /search-index.json`
+///
+/// The compact index the search palette queries in the browser. It is regenerated with
+/// the server-side search index, so its `ETag` changes whenever the content does.
+pub async fn search_index(headers: HeaderMap) -> Response {
+    let Ok(blog_service) = get_blog_service() else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Blog storage is unavailable",
+        );
+    };
+    let Ok(book_service) = get_book_service() else {
+        return error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Book storage is unavailable",
+        );
+    };
+
+    match client_index_json(blog_service, book_service).await {
+        Ok((json, generated_at)) => conditional_response(
+            &headers,
+            JSON_CONTENT_TYPE,
+            json.as_bytes().to_vec(),
+            Some(generated_at),
+        ),
+        Err(e) => {
+            logging::error!("Failed to build the client search index: {:?}", e);
+            error_response(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to build the search index",
+            )
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt::Write;
+
+use axum::{
+    extract::Path,
+    http::{HeaderMap, StatusCode},
+    response::Response,
+};
+use chrono::{DateTime, Utc};
+use leptos::logging;
+
+use crate::{
+    app::STATIC_ROUTES,
+    handlers::{conditional_response, error_response, site_url},
+    models::blog::tag_slug_path,
+    services::{
+        config::{try_get_config, RobotsConfig},
+        cosmos::blog_repository::get_blog_service,
+    },
+    utils::url::absolute_url,
+};
+
+/// Maximum number of URLs per sitemap file allowed by the sitemap protocol.
+const SITEMAP_URL_LIMIT: usize = 50_000;
+
+const XML_CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+const TEXT_CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+struct SitemapUrl {
+    loc: String,
+    lastmod: Option<DateTime<Utc>>,
+}
+
+/// `GET /sitemap.xml`
+///
+/// Serves a single `<urlset>` while the site fits into one sitemap and a
+/// `<sitemapindex>` pointing at `/sitemaps/{page}` once it does not.
+pub async fn sitemap(headers: HeaderMap) -> Response {
+    let (base_url, urls) = match collect_urls().await {
+        Ok(collected) => collected,
+        Err(response) => return response,
+    };
+    let last_modified = urls.iter().filter_map(|url| url.lastmod).max();
+
+    let body = if urls.len() <= SITEMAP_URL_LIMIT {
+        render_urlset(&urls)
+    } else {
+        let pages: Vec<Option<DateTime<Utc>>> = urls
+            .chunks(SITEMAP_URL_LIMIT)
+            .map(|chunk| chunk.iter().filter_map(|url| url.lastmod).max())
+            .collect();
+        render_index(base_url, &pages)
+    };
+
+    conditional_response(&headers, XML_CONTENT_TYPE, body, last_modified)
+}
+
+/// `GET /sitemaps/{page}`, one page of a sitemap index (1-based).
+pub async fn sitemap_page(Path(page): Path<usize>, headers: HeaderMap) -> Response {
+    let (_, urls) = match collect_urls().await {
+        Ok(collected) => collected,
+        Err(response) => return response,
+    };
+
+    let Some(chunk) = page
+        .checked_sub(1)
+        .and_then(|index| urls.chunks(SITEMAP_URL_LIMIT).nth(index))
+    else {
+        return error_response(StatusCode::NOT_FOUND, "Sitemap page not found");
+    };
+
+    conditional_response(
+        &headers,
+        XML_CONTENT_TYPE,
+        render_urlset(chunk),
+        chunk.iter().filter_map(|url| url.lastmod).max(),
+    )
+}
+
+/// `GET /robots.txt`
+#[allow(clippy::unused_async)]
+pub async fn robots_txt(headers: HeaderMap) -> Response {
+    let base_url = match site_url() {
+        Ok(base_url) => base_url,
+        Err(response) => return response,
+    };
+    let robots = try_get_config()
+        .map(|config| config.robots.clone())
+        .unwrap_or_default();
+
+    conditional_response(
+        &headers,
+        TEXT_CONTENT_TYPE,
+        render_robots(base_url, &robots),
+        None,
+    )
+}
+
+/// Every URL in the sitemap, together with the base URL they were made absolute against.
+async fn collect_urls() -> Result<(&'static str, Vec<SitemapUrl>), Response> {
+    let base_url = site_url()?;
+    let blog_service = get_blog_service().map_err(|e| {
+        logging::error!("Blog service unavailable for sitemap: {}", e);
+        error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Blog storage is unavailable",
+        )
+    })?;
+
+    let posts = blog_service.list_sitemap_entries().await.map_err(|e| {
+        logging::error!("Failed to fetch posts for sitemap: {:?}", e);
+        error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load posts")
+    })?;
+    let tags = blog_service.list_tag_counts().await.map_err(|e| {
+        logging::error!("Failed to fetch tags for sitemap: {:?}", e);
+        error_response(StatusCode::INTERNAL_SERVER_ERROR, "Failed to load tags")
+    })?;
+
+    let newest_post = posts.iter().map(|post| post.updated_at).max();
+
+    let mut urls: Vec<SitemapUrl> = STATIC_ROUTES
+        .iter()
+        .map(|route| SitemapUrl {
+            loc: absolute_url(base_url, route),
+            // Listing pages change whenever a post does
+            lastmod: if route.starts_with("/blog") || *route == "/" {
+                newest_post
+            } else {
+                None
+            },
+        })
+        .collect();
+
+    urls.extend(posts.iter().map(|post| SitemapUrl {
+        loc: absolute_url(base_url, &post.path()),
+        lastmod: Some(post.updated_at),
+    }));
+
+    urls.extend(tags.iter().map(|tag| SitemapUrl {
+        loc: absolute_url(base_url, &tag_slug_path(&tag.slug)),
+        lastmod: None,
+    }));
+
+    Ok((base_url, urls))
+}
+
+fn render_urlset(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for url in urls {
+        let _ = write!(
+            xml,
+            "  <url>\n    <loc>{}</loc>\n",
+            html_escape::encode_text(&url.loc)
+        );
+        if let Some(lastmod) = url.lastmod {
+            let _ = writeln!(xml, "    <lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"));
+        }
+        xml.push_str("  </url>\n");
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+fn render_index(base_url: &str, pages: &[Option<DateTime<Utc>>]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for (index, lastmod) in pages.iter().enumerate() {
+        let _ = write!(
+            xml,
+            "  <sitemap>\n    <loc>{}</loc>\n",
+            html_escape::encode_text(&absolute_url(base_url, &format!("/sitemaps/{}", index + 1)))
+        );
+        if let Some(lastmod) = lastmod {
+            let _ = writeln!(xml, "    <lastmod>{}</lastmod>", lastmod.format("%Y-%m-%d"));
+        }
+        xml.push_str("  </sitemap>\n");
+    }
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+fn render_robots(base_url: &str, robots: &RobotsConfig) -> String {
+    let mut text = String::from("User-agent: *\n");
+    if robots.allow_indexing {
+        for path in &robots.disallow {
+            let _ = writeln!(text, "Disallow: {path}");
+        }
+        if robots.disallow.is_empty() {
+            text.push_str("Allow: /\n");
+        }
+    } else {
+        text.push_str("Disallow: /\n");
+    }
+    let _ = writeln!(
+        text,
+        "\nSitemap: {}",
+        absolute_url(base_url, "/sitemap.xml")
+    );
+    text
+}
@@ -2,8 +2,11 @@ pub mod api;
 pub mod app;
 pub mod components;
 pub mod constants;
+#[cfg(feature = "ssr")]
+pub mod handlers;
 pub mod models;
 pub mod pages;
+pub mod rendering;
 pub mod services;
 pub mod test_blog_code;
 pub mod utils;
@@ -11,7 +14,7 @@ pub mod utils;
 #[cfg(feature = "hydrate")]
 #[wasm_bindgen::prelude::wasm_bindgen]
 pub fn hydrate() {
-    use crate::app::*;
+    use crate::app::App;
 
     console_error_panic_hook::set_once();
     leptos::mount::hydrate_body(App);
@@ -1,14 +1,19 @@
 #[cfg(feature = "ssr")]
 #[tokio::main]
 async fn main() -> color_eyre::Result<()> {
-    use axum::Router;
+    use axum::{routing::get, Router};
     use color_eyre;
+    use color_eyre::eyre::{eyre, WrapErr};
     use dotenv::dotenv;
     use leptos::logging::log;
     use leptos::prelude::*;
     use leptos_axum::{generate_route_list, LeptosRoutes};
-    use mike_dev_blog::app::*;
-    use mike_dev_blog::services::config::init_config;
+    use mike_dev_blog::app::{shell, App};
+    use mike_dev_blog::handlers::{feeds, og_image, search_index, sitemap};
+    use mike_dev_blog::services::config::get_config;
+    use mike_dev_blog::services::cosmos::blog_repository::get_blog_service;
+    use mike_dev_blog::services::preflight::run_preflight;
+    use mike_dev_blog::services::{related_posts, scheduler, search};
     use std::sync::Arc;
     use tracing_subscriber;
     use tracing_subscriber::fmt::init;
@@ -20,16 +25,60 @@ async fn main() -> color_eyre::Result<()> {
 
     dotenv().ok();
 
-    let app_config = init_config();
-    let shared_config = Arc::new(app_config);
+    // `--check` runs the preflight checks and exits without starting the server
+    let check_only = std::env::args().skip(1).any(|arg| arg == "--check");
+
+    let conf = get_configuration(None).wrap_err("Failed to read the Leptos configuration")?;
+
+    if let Err(report) = run_preflight(&conf.leptos_options).await {
+        eprintln!("{:?}", miette::Report::new(report));
+        std::process::exit(1);
+    }
+
+    if check_only {
+        log!("All preflight checks passed");
+        return Ok(());
+    }
+
+    // Posts stored with the old `published` flag would otherwise all count as drafts and
+    // silently drop out of every list, feed and sitemap, so don't serve until they are migrated
+    let blog_service = get_blog_service().map_err(|e| eyre!("Blog storage is unavailable: {e}"))?;
+    let migrated = blog_service
+        .migrate_visibility()
+        .await
+        .wrap_err("Failed to migrate posts to explicit visibility")?;
+    if migrated > 0 {
+        log!("Migrated {migrated} posts to explicit visibility");
+    }
+    let backfilled = blog_service
+        .backfill_content_stats()
+        .await
+        .wrap_err("Failed to backfill content stats")?;
+    if backfilled > 0 {
+        log!("Stored content stats for {backfilled} posts");
+    }
+
+    scheduler::spawn_publish_scheduler();
+    related_posts::spawn_cache_invalidation();
+    search::spawn_search_indexer();
+
+    let shared_config = Arc::new(get_config().clone());
 
-    let conf = get_configuration(None).unwrap();
     let addr = conf.leptos_options.site_addr;
     let leptos_options = conf.leptos_options;
     // Generate the list of routes in your Leptos App
     let routes = generate_route_list(App);
 
     let app = Router::new()
+        .route("/feed.xml", get(feeds::rss_feed))
+        .route("/atom.xml", get(feeds::atom_feed))
+        .route("/feed.json", get(feeds::json_feed))
+        .route("/blog/tags/{tag}/feed.xml", get(feeds::tag_rss_feed))
+        .route("/sitemap.xml", get(sitemap::sitemap))
+        .route("/sitemaps/{page}", get(sitemap::sitemap_page))
+        .route("/robots.txt", get(sitemap::robots_txt))
+        .route("/og/{file}", get(og_image::og_image))
+        .route("/search-index.json", get(search_index::search_index))
         .leptos_routes(&leptos_options, routes, {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
@@ -41,10 +90,12 @@ async fn main() -> color_eyre::Result<()> {
     // run our app with hyper
     // `axum::Server` is a re-export of `hyper::Server`
     log!("listening on http://{}", &addr);
-    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    Ok(axum::serve(listener, app.into_make_service())
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .wrap_err_with(|| format!("Failed to bind to {addr}"))?;
+    axum::serve(listener, app.into_make_service())
         .await
-        .unwrap())
+        .wrap_err("Server error")
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -0,0 +1,160 @@
+use std::cmp::Reverse;
+
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::blog::BlogPost;
+use crate::utils::date_formatter::format_month_year;
+
+/// A year, or a month within it, of the post archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ArchivePeriod {
+    pub year: i32,
+    /// One-based month, `None` for the whole year.
+    pub month: Option<u32>,
+}
+
+impl ArchivePeriod {
+    /// Parses the `:year` and optional `:month` segments of an archive URL.
+    #[must_use]
+    pub fn parse(year: &str, month: Option<&str>) -> Option<Self> {
+        let year = year.parse().ok()?;
+        let month = match month {
+            Some(month) => Some(
+                month
+                    .parse()
+                    .ok()
+                    .filter(|month| (1..=12).contains(month))?,
+            ),
+            None => None,
+        };
+        Some(Self { year, month })
+    }
+
+    /// Start of the period, `None` for years chrono cannot represent.
+    #[must_use]
+    pub fn start(self) -> Option<DateTime<Utc>> {
+        Utc.with_ymd_and_hms(self.year, self.month.unwrap_or(1), 1, 0, 0, 0)
+            .single()
+    }
+
+    /// "2024" or "March 2024".
+    #[must_use]
+    pub fn label(self) -> String {
+        match (self.month, self.start()) {
+            (Some(_), Some(start)) => format_month_year(start),
+            (Some(month), None) => format!("{}-{month:02}", self.year),
+            (None, _) => self.year.to_string(),
+        }
+    }
+
+    #[must_use]
+    pub fn path(self) -> String {
+        match self.month {
+            Some(month) => format!("/blog/archive/{}/{month:02}", self.year),
+            None => format!("/blog/archive/{}", self.year),
+        }
+    }
+
+    /// The year a month belongs to.
+    #[must_use]
+    pub fn year_period(self) -> Self {
+        Self {
+            year: self.year,
+            month: None,
+        }
+    }
+}
+
+/// Number of published posts in one month.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveMonth {
+    pub period: ArchivePeriod,
+    pub count: usize,
+}
+
+/// Published posts per month of one year, months in calendar order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArchiveYear {
+    pub period: ArchivePeriod,
+    pub count: usize,
+    pub months: Vec<ArchiveMonth>,
+}
+
+impl ArchiveYear {
+    /// Groups monthly counts into years, newest year first.
+    #[must_use]
+    pub fn group(months: Vec<ArchiveMonth>) -> Vec<Self> {
+        let mut years: Vec<Self> = Vec::new();
+        for month in months {
+            let year = month.period.year_period();
+            match years.iter_mut().find(|entry| entry.period == year) {
+                Some(entry) => {
+                    entry.count += month.count;
+                    entry.months.push(month);
+                }
+                None => years.push(Self {
+                    period: year,
+                    count: month.count,
+                    months: vec![month],
+                }),
+            }
+        }
+
+        years.sort_by_key(|year| Reverse(year.period.year));
+        for year in &mut years {
+            year.months.sort_by_key(|month| month.period.month);
+        }
+        years
+    }
+}
+
+/// Published posts of an archive period, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivePosts {
+    pub period: ArchivePeriod,
+    pub posts: Vec<BlogPost>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_periods() {
+        let march = ArchivePeriod {
+            year: 2024,
+            month: Some(3),
+        };
+
+        assert_eq!(ArchivePeriod::parse("2024", Some("03")), Some(march));
+        assert_eq!(march.path(), "/blog/archive/2024/03");
+        assert_eq!(march.label(), "March 2024");
+        assert_eq!(march.year_period().path(), "/blog/archive/2024");
+        assert_eq!(ArchivePeriod::parse("2024", Some("13")), None);
+        assert_eq!(ArchivePeriod::parse("last-year", None), None);
+    }
+
+    #[test]
+    fn groups_months_into_years() {
+        let month = |year, month, count| ArchiveMonth {
+            period: ArchivePeriod {
+                year,
+                month: Some(month),
+            },
+            count,
+        };
+
+        let years = ArchiveYear::group(vec![
+            month(2023, 11, 1),
+            month(2024, 5, 2),
+            month(2024, 1, 3),
+        ]);
+
+        assert_eq!(years.len(), 2);
+        assert_eq!(years[0].period.year, 2024);
+        assert_eq!(years[0].count, 5);
+        assert_eq!(years[0].months[0].period.month, Some(1));
+        assert_eq!(years[1].count, 1);
+    }
+}
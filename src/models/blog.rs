@@ -2,17 +2,98 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::models::series::SeriesNavigation;
+use crate::rendering::{render_markdown, RenderedContent};
+use crate::utils::content_analysis::{analyze_html, ContentStats};
+use crate::utils::slug::slugify;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlogPost {
     pub id: String,
+    /// URL-safe identifier generated from the title, unique across all posts.
+    #[serde(default)]
+    pub slug: String,
+    /// Slugs the post was published under before a rename; they redirect to `slug`.
+    #[serde(default)]
+    pub previous_slugs: Vec<String>,
     pub title: String,
     pub content: String,
     pub author: String,
-    pub published: bool,
+    /// Who can find and open the post. Posts stored with the old `published` flag are
+    /// converted at startup by `BlogService::migrate_visibility`.
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// When a draft goes public. The scheduler then makes the post [`Visibility::Public`]
+    /// and this its publication date. Stored in whole seconds, see
+    /// [`storage_timestamp`](crate::utils::datetime::storage_timestamp).
+    #[serde(default, with = "crate::utils::datetime::storage_time")]
+    pub publish_at: Option<DateTime<Utc>>,
+    /// When a public or unlisted post is archived.
+    #[serde(default, with = "crate::utils::datetime::storage_time")]
+    pub unpublish_at: Option<DateTime<Utc>>,
     pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub image_url: Option<String>,
+    /// Whether the post page shows a table of contents next to the content. Defaults to
+    /// shown; admins toggle it with `set_show_toc_server`.
+    #[serde(default = "default_show_toc")]
+    pub show_toc: bool,
+    /// Word counts, reading time and excerpts computed from `content` when the post is
+    /// saved. Missing on posts saved before stats existed until the startup backfill,
+    /// see [`BlogPost::stats`].
+    #[serde(default)]
+    pub content_stats: Option<ContentStats>,
+    /// Id of the series the post belongs to, see [`Series`](crate::models::series::Series).
+    #[serde(default)]
+    pub series_id: Option<String>,
+    /// One-based part number within the series, kept in sync with `Series::post_ids`.
+    #[serde(default)]
+    pub series_position: Option<u32>,
+}
+
+fn default_show_toc() -> bool {
+    true
+}
+
+/// Who can see a post.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    /// Work in progress, only visible to admins and through preview links.
+    #[default]
+    Draft,
+    /// Reachable by its URL but left out of lists, feeds, sitemaps and search.
+    Unlisted,
+    /// Listed everywhere.
+    Public,
+    /// Withdrawn after being public. Its URL answers `410 Gone`.
+    Archived,
+}
+
+impl Visibility {
+    /// Whether the post shows up in lists, feeds, sitemaps and search.
+    #[must_use]
+    pub fn is_listed(self) -> bool {
+        self == Self::Public
+    }
+
+    /// Whether readers can open the post by its URL.
+    #[must_use]
+    pub fn is_reachable(self) -> bool {
+        matches!(self, Self::Public | Self::Unlisted)
+    }
+
+    /// The value stored in Cosmos DB, for use in queries.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Unlisted => "unlisted",
+            Self::Public => "public",
+            Self::Archived => "archived",
+        }
+    }
 }
 
 impl BlogPost {
@@ -27,14 +108,315 @@ impl BlogPost {
         let now = Utc::now();
         Self {
             id: Uuid::new_v4().to_string(),
+            slug: slugify(&title),
+            previous_slugs: Vec::new(),
             title,
             content,
             author,
-            published: false,
+            visibility: Visibility::Draft,
+            publish_at: None,
+            unpublish_at: None,
             tags,
             created_at: now,
             updated_at: now,
             image_url,
+            show_toc: true,
+            content_stats: None,
+            series_id: None,
+            series_position: None,
+        }
+    }
+
+    /// Visibility of the post at `now` with its schedule applied. A scheduled draft
+    /// counts as public from its publish time on, even before the scheduler has
+    /// updated `visibility`, and an expired post counts as archived.
+    #[must_use]
+    pub fn visibility_at(&self, now: DateTime<Utc>) -> Visibility {
+        let mut visibility = self.visibility;
+        if visibility == Visibility::Draft && self.publish_at.is_some_and(|at| at <= now) {
+            visibility = Visibility::Public;
+        }
+        if visibility.is_reachable() && self.unpublish_at.is_some_and(|at| at <= now) {
+            visibility = Visibility::Archived;
         }
+        visibility
+    }
+
+    /// Whether the post shows up in lists, feeds and sitemaps at `now`.
+    #[must_use]
+    pub fn is_listed_at(&self, now: DateTime<Utc>) -> bool {
+        self.visibility_at(now).is_listed()
+    }
+
+    /// Whether readers can open the post by its URL at `now`.
+    #[must_use]
+    pub fn is_reachable_at(&self, now: DateTime<Utc>) -> bool {
+        self.visibility_at(now).is_reachable()
+    }
+
+    /// Brings `visibility` in line with the schedule at `now`. Returns whether it changed.
+    pub fn apply_schedule(&mut self, now: DateTime<Utc>) -> bool {
+        let visibility = self.visibility_at(now);
+        if visibility == self.visibility {
+            return false;
+        }
+
+        if self.visibility == Visibility::Draft {
+            // The scheduled time becomes the publication date. Clearing it makes
+            // turning the post back into a draft by hand later on stick.
+            if let Some(publish_at) = self.publish_at.take() {
+                self.created_at = publish_at;
+                self.updated_at = publish_at;
+            }
+        }
+        if visibility == Visibility::Archived {
+            self.unpublish_at = None;
+        }
+        self.visibility = visibility;
+        true
+    }
+
+    /// Recomputes `content_stats` from the current content. Called before every save.
+    pub fn refresh_stats(&mut self) {
+        self.content_stats = Some(analyze_html(&render_markdown(&self.content).html));
+    }
+
+    /// Stored content stats, or stats computed on the fly for posts that have none yet.
+    /// Those are filled in at startup by `BlogService::backfill_content_stats`.
+    #[must_use]
+    pub fn stats(&self) -> ContentStats {
+        self.content_stats
+            .clone()
+            .unwrap_or_else(|| analyze_html(&render_markdown(&self.content).html))
+    }
+
+    /// Canonical path of the post, see [`post_path`].
+    #[must_use]
+    pub fn path(&self) -> String {
+        post_path(&self.slug, &self.id)
+    }
+
+    /// Path of the generated social card image for the post.
+    #[must_use]
+    pub fn og_image_path(&self) -> String {
+        format!("/og/{}.png", slug_or_id(&self.slug, &self.id))
+    }
+}
+
+/// A post together with its rendered content, as shown on the post page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlogArticle {
+    pub post: BlogPost,
+    pub content: RenderedContent,
+    /// Navigation between the parts of the series the post belongs to.
+    pub series: Option<SeriesNavigation>,
+    /// Public base URL of the site, for the absolute links in the page metadata.
+    pub site_url: String,
+}
+
+impl BlogArticle {
+    /// Renders the Markdown content of `post`, which is published under `site_url`.
+    #[must_use]
+    pub fn render(post: BlogPost, site_url: String) -> Self {
+        let content = render_markdown(&post.content);
+        Self {
+            post,
+            content,
+            series: None,
+            site_url,
+        }
+    }
+}
+
+/// Result of resolving a `/blog/:slug` URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum BlogLookup {
+    /// The URL is the canonical address of the post.
+    Found(Box<BlogArticle>),
+    /// The URL is an old UUID link or a renamed slug; the post now lives at `slug`.
+    Moved { slug: String },
+    /// The post was archived and is no longer available.
+    Gone { title: String },
+}
+
+/// A tag together with the number of published posts carrying it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCount {
+    pub tag: String,
+    pub slug: String,
+    pub count: usize,
+}
+
+/// Published posts for a single tag landing page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagPosts {
+    pub tag: String,
+    pub posts: Vec<BlogPost>,
+}
+
+/// Segments under `/blog/` that belong to other routes and so can't be post slugs.
+pub const RESERVED_POST_SLUGS: &[&str] = &["tags", "archive", "preview"];
+
+/// Canonical path of the post with `slug` and `id`. Posts stored before slugs existed
+/// fall back to their id.
+#[must_use]
+pub fn post_path(slug: &str, id: &str) -> String {
+    format!("/blog/{}", slug_or_id(slug, id))
+}
+
+/// The part of a post's URLs that identifies it: its slug, or its id if it has none.
+fn slug_or_id<'a>(slug: &'a str, id: &'a str) -> &'a str {
+    if slug.is_empty() {
+        id
+    } else {
+        slug
+    }
+}
+
+/// Path of the landing page for `tag`.
+#[must_use]
+pub fn tag_path(tag: &str) -> String {
+    tag_slug_path(&slugify(tag))
+}
+
+/// Path of the landing page for the tag whose slug is `slug`.
+#[must_use]
+pub fn tag_slug_path(slug: &str) -> String {
+    format!("/blog/tags/{slug}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    fn post(visibility: Visibility) -> BlogPost {
+        let mut post = BlogPost::new(
+            "Scheduling posts".to_string(),
+            "Content".to_string(),
+            "Mike".to_string(),
+            Vec::new(),
+            None,
+        );
+        post.visibility = visibility;
+        post.created_at = now() - Duration::days(7);
+        post.updated_at = post.created_at;
+        post
+    }
+
+    #[test]
+    fn unscheduled_posts_keep_their_visibility() {
+        for visibility in [
+            Visibility::Draft,
+            Visibility::Unlisted,
+            Visibility::Public,
+            Visibility::Archived,
+        ] {
+            let post = post(visibility);
+
+            assert_eq!(post.visibility_at(now()), visibility);
+            assert_eq!(post.is_listed_at(now()), visibility == Visibility::Public);
+            assert_eq!(
+                post.is_reachable_at(now()),
+                matches!(visibility, Visibility::Public | Visibility::Unlisted)
+            );
+        }
+    }
+
+    #[test]
+    fn scheduled_drafts_go_public_at_their_publish_time() {
+        let mut draft = post(Visibility::Draft);
+        draft.publish_at = Some(now() + Duration::hours(1));
+
+        assert_eq!(draft.visibility_at(now()), Visibility::Draft);
+        assert!(!draft.is_reachable_at(now()));
+        assert_eq!(
+            draft.visibility_at(now() + Duration::hours(1)),
+            Visibility::Public
+        );
+        assert!(draft.is_listed_at(now() + Duration::days(1)));
+    }
+
+    #[test]
+    fn reachable_posts_expire_at_their_unpublish_time() {
+        for visibility in [Visibility::Public, Visibility::Unlisted] {
+            let mut expiring = post(visibility);
+            expiring.unpublish_at = Some(now());
+
+            assert_eq!(
+                expiring.visibility_at(now() - Duration::seconds(1)),
+                visibility
+            );
+            assert_eq!(expiring.visibility_at(now()), Visibility::Archived);
+        }
+
+        // Expiry never makes an unpublished draft reachable or archived
+        let mut draft = post(Visibility::Draft);
+        draft.unpublish_at = Some(now() - Duration::days(1));
+        assert_eq!(draft.visibility_at(now()), Visibility::Draft);
+    }
+
+    #[test]
+    fn publishing_moves_the_publication_date_to_the_schedule() {
+        let publish_at = now() - Duration::minutes(5);
+        let mut draft = post(Visibility::Draft);
+        draft.publish_at = Some(publish_at);
+
+        assert!(draft.apply_schedule(now()));
+        assert_eq!(draft.visibility, Visibility::Public);
+        assert_eq!(draft.publish_at, None);
+        assert_eq!(draft.created_at, publish_at);
+        assert_eq!(draft.updated_at, publish_at);
+        assert!(!draft.apply_schedule(now()));
+    }
+
+    #[test]
+    fn archiving_clears_the_unpublish_time() {
+        let mut public = post(Visibility::Public);
+        let created_at = public.created_at;
+        public.unpublish_at = Some(now() - Duration::minutes(5));
+
+        assert!(public.apply_schedule(now()));
+        assert_eq!(public.visibility, Visibility::Archived);
+        assert_eq!(public.unpublish_at, None);
+        assert_eq!(public.created_at, created_at);
+
+        // A draft whose whole window has passed is archived in one step
+        let mut draft = post(Visibility::Draft);
+        draft.publish_at = Some(now() - Duration::days(2));
+        draft.unpublish_at = Some(now() - Duration::days(1));
+
+        assert!(draft.apply_schedule(now()));
+        assert_eq!(draft.visibility, Visibility::Archived);
+        assert_eq!(draft.publish_at, None);
+        assert_eq!(draft.unpublish_at, None);
+        assert_eq!(draft.created_at, now() - Duration::days(2));
+    }
+
+    #[test]
+    fn unscheduled_posts_are_left_alone() {
+        let mut draft = post(Visibility::Draft);
+
+        assert!(!draft.apply_schedule(now()));
+        assert_eq!(draft.visibility, Visibility::Draft);
+    }
+
+    #[test]
+    fn post_urls_fall_back_to_the_id_without_a_slug() {
+        let mut post = post(Visibility::Public);
+        post.slug = "scheduling-posts".to_string();
+
+        assert_eq!(post.path(), "/blog/scheduling-posts");
+        assert_eq!(post.og_image_path(), "/og/scheduling-posts.png");
+
+        post.slug = String::new();
+        assert_eq!(post.path(), format!("/blog/{}", post.id));
+        assert_eq!(post.og_image_path(), format!("/og/{}.png", post.id));
     }
 }
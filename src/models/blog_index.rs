@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::blog::{BlogPost, TagCount};
+use crate::utils::slug::slugify;
+
+/// Posts per page of the blog index.
+pub const POSTS_PER_PAGE: u32 = 12;
+
+/// Order of the posts on the blog index.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PostSort {
+    #[default]
+    Newest,
+    Oldest,
+    Title,
+}
+
+impl PostSort {
+    #[must_use]
+    pub fn all() -> [Self; 3] {
+        [Self::Newest, Self::Oldest, Self::Title]
+    }
+
+    /// Value of the `sort` query parameter.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Newest => "newest",
+            Self::Oldest => "oldest",
+            Self::Title => "title",
+        }
+    }
+
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Newest => "Newest first",
+            Self::Oldest => "Oldest first",
+            Self::Title => "Title A-Z",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::all().into_iter().find(|sort| sort.as_str() == value)
+    }
+}
+
+/// Filters, order and page of the blog index, as carried in the `/blog` query string
+/// (`?tag=rust&year=2024&sort=oldest&page=2`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlogIndexQuery {
+    /// One-based page number.
+    pub page: u32,
+    /// Tag slug, see [`tag_path`](crate::models::blog::tag_path).
+    pub tag: Option<String>,
+    pub year: Option<i32>,
+    pub sort: PostSort,
+}
+
+impl Default for BlogIndexQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            tag: None,
+            year: None,
+            sort: PostSort::default(),
+        }
+    }
+}
+
+impl BlogIndexQuery {
+    /// Reads the query from URL parameters. Missing, empty and invalid values fall back
+    /// to the defaults, so hand-edited URLs still show something sensible.
+    #[must_use]
+    pub fn from_params(param: impl Fn(&str) -> Option<String>) -> Self {
+        let value = |key: &str| param(key).filter(|value| !value.trim().is_empty());
+
+        Self {
+            page: value("page")
+                .and_then(|page| page.trim().parse().ok())
+                .filter(|page| *page >= 1)
+                .unwrap_or(1),
+            tag: value("tag").map(|tag| slugify(&tag)),
+            year: value("year").and_then(|year| year.trim().parse().ok()),
+            sort: value("sort")
+                .and_then(|sort| PostSort::parse(sort.trim()))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The same filters and order on another page.
+    #[must_use]
+    pub fn with_page(&self, page: u32) -> Self {
+        Self {
+            page,
+            ..self.clone()
+        }
+    }
+
+    /// Whether any filter narrows the posts down.
+    #[must_use]
+    pub fn is_filtered(&self) -> bool {
+        self.tag.is_some() || self.year.is_some()
+    }
+
+    /// Shareable URL path of this state. Default values are left out, so the first
+    /// unfiltered page is plain `/blog`.
+    #[must_use]
+    pub fn path(&self) -> String {
+        let mut params = Vec::new();
+        if let Some(tag) = &self.tag {
+            // Slugs never need escaping
+            params.push(format!("tag={tag}"));
+        }
+        if let Some(year) = self.year {
+            params.push(format!("year={year}"));
+        }
+        if self.sort != PostSort::default() {
+            params.push(format!("sort={}", self.sort.as_str()));
+        }
+        if self.page > 1 {
+            params.push(format!("page={}", self.page));
+        }
+
+        if params.is_empty() {
+            "/blog".to_string()
+        } else {
+            format!("/blog?{}", params.join("&"))
+        }
+    }
+}
+
+/// One page of the blog index together with what the filter controls offer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlogIndexPage {
+    /// The query as understood by the server, with unknown tags and years kept.
+    pub query: BlogIndexQuery,
+    pub posts: Vec<BlogPost>,
+    /// Number of posts matching the filters across all pages.
+    pub total: usize,
+    pub page_count: u32,
+    /// Display name of the filtered tag.
+    pub tag_name: Option<String>,
+    pub tags: Vec<TagCount>,
+    /// Years with published posts, newest first.
+    pub years: Vec<i32>,
+    /// Public base URL of the site, for the absolute `rel=prev/next` links.
+    pub site_url: String,
+}
+
+impl BlogIndexPage {
+    /// Page numbers to link to, with `None` standing for a gap: the first and last
+    /// page and two pages either side of the current one.
+    #[must_use]
+    pub fn page_links(&self) -> Vec<Option<u32>> {
+        let current = self.query.page;
+        let mut links = Vec::new();
+        for page in 1..=self.page_count {
+            let near = page.abs_diff(current) <= 2;
+            if page == 1 || page == self.page_count || near {
+                links.push(Some(page));
+            } else if links.last().is_some_and(Option::is_some) {
+                links.push(None);
+            }
+        }
+        links
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params<'a>(pairs: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| {
+            pairs
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| (*value).to_string())
+        }
+    }
+
+    #[test]
+    fn reads_and_writes_the_query_string() {
+        let query = BlogIndexQuery::from_params(params(&[
+            ("tag", "Web Dev"),
+            ("year", "2024"),
+            ("sort", "oldest"),
+            ("page", "3"),
+        ]));
+
+        assert_eq!(query.tag.as_deref(), Some("web-dev"));
+        assert_eq!(
+            query.path(),
+            "/blog?tag=web-dev&year=2024&sort=oldest&page=3"
+        );
+        assert_eq!(
+            query.with_page(1).path(),
+            "/blog?tag=web-dev&year=2024&sort=oldest"
+        );
+    }
+
+    #[test]
+    fn invalid_values_fall_back_to_defaults() {
+        let query = BlogIndexQuery::from_params(params(&[
+            ("tag", " "),
+            ("year", "soon"),
+            ("sort", "random"),
+            ("page", "0"),
+        ]));
+
+        assert_eq!(query, BlogIndexQuery::default());
+        assert_eq!(query.path(), "/blog");
+    }
+
+    #[test]
+    fn page_links_elide_distant_pages() {
+        let page = BlogIndexPage {
+            query: BlogIndexQuery::default().with_page(6),
+            posts: Vec::new(),
+            total: 120,
+            page_count: 10,
+            tag_name: None,
+            tags: Vec::new(),
+            years: Vec::new(),
+            site_url: String::new(),
+        };
+
+        assert_eq!(
+            page.page_links(),
+            vec![
+                Some(1),
+                None,
+                Some(4),
+                Some(5),
+                Some(6),
+                Some(7),
+                Some(8),
+                None,
+                Some(10)
+            ]
+        );
+    }
+}
@@ -23,6 +23,20 @@ pub struct Book {
     pub last_updated: DateTime<Utc>,
 }
 
+impl Book {
+    /// Id of the book's card on the books page.
+    #[must_use]
+    pub fn anchor(&self) -> String {
+        format!("book-{}", self.id)
+    }
+
+    /// Link to the book's card on the books page.
+    #[must_use]
+    pub fn path(&self) -> String {
+        format!("/books#{}", self.anchor())
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum BookCategory {
     #[serde(rename = "rust")]
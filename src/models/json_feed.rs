@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::models::blog::BlogPost;
+
+/// Version URL every JSON Feed 1.1 document starts with.
+pub const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// A JSON Feed 1.1 document, see <https://www.jsonfeed.org/version/1.1/>.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonFeed {
+    pub version: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_page_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feed_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<JsonFeedAuthor>,
+    pub items: Vec<JsonFeedItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonFeedItem {
+    pub id: String,
+    pub url: String,
+    pub title: String,
+    pub content_html: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub date_published: DateTime<Utc>,
+    pub date_modified: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<JsonFeedAuthor>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonFeedAuthor {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+}
+
+impl JsonFeedAuthor {
+    #[must_use]
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: None,
+            avatar: None,
+        }
+    }
+}
+
+impl JsonFeedItem {
+    /// Builds a feed item from a post. `url` must be absolute, and `content_html`
+    /// and `summary` are passed in so the caller controls rendering and sanitizing.
+    #[must_use]
+    pub fn from_post(
+        post: &BlogPost,
+        url: String,
+        content_html: String,
+        summary: Option<String>,
+    ) -> Self {
+        Self {
+            id: post.id.clone(),
+            url,
+            title: post.title.clone(),
+            content_html,
+            summary,
+            image: post.image_url.clone(),
+            date_published: post.created_at,
+            date_modified: post.updated_at,
+            authors: vec![JsonFeedAuthor::named(post.author.clone())],
+            tags: post.tags.clone(),
+        }
+    }
+}
@@ -1,2 +1,8 @@
+pub mod archive;
 pub mod blog;
+pub mod blog_index;
 pub mod books;
+pub mod json_feed;
+pub mod preview;
+pub mod search;
+pub mod series;
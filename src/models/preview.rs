@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::blog::{BlogArticle, BlogPost};
+
+/// A revocable link that shows one revision of a post to reviewers before it is public.
+///
+/// The link keeps a snapshot of the post, so later edits don't change what the
+/// reviewer sees; a new link has to be created for the new revision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewLink {
+    pub id: String,
+    pub post_id: String,
+    /// Partition key, shared with the previewed post.
+    pub author: String,
+    pub post: BlogPost,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    #[serde(default)]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl PreviewLink {
+    #[must_use]
+    pub fn new(post: BlogPost, expires_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4().to_string(),
+            post_id: post.id.clone(),
+            author: post.author.clone(),
+            post,
+            created_at: Utc::now(),
+            expires_at,
+            revoked_at: None,
+        }
+    }
+
+    /// Whether the link may still be opened at `now`.
+    #[must_use]
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at > now
+    }
+}
+
+/// A preview link without its post snapshot, for listing the links of a post.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreviewLinkSummary {
+    pub id: String,
+    pub post_id: String,
+    pub post_title: String,
+    /// `updated_at` of the previewed revision.
+    pub revision: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    #[serde(default)]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// A freshly created preview link together with the URL to share.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedPreviewLink {
+    pub url: String,
+    pub link: PreviewLinkSummary,
+}
+
+/// The rendered revision shown at `/blog/preview/:token`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreviewArticle {
+    pub article: BlogArticle,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Path of the preview page for `token`.
+#[must_use]
+pub fn preview_path(token: &str) -> String {
+    format!("/blog/preview/{token}")
+}
@@ -0,0 +1,294 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::text::{edit_distance, is_term, terms};
+
+/// Longest query accepted, in characters. Longer input is cut off.
+pub const MAX_QUERY_CHARS: usize = 200;
+
+/// Format version of [`ClientSearchIndex`]. Bump it whenever the format changes, so
+/// browsers still running an older bundle stop using it.
+pub const CLIENT_INDEX_VERSION: u32 = 1;
+
+const TITLE_WEIGHT: f64 = 4.0;
+const TAG_WEIGHT: f64 = 3.0;
+const WORD_WEIGHT: f64 = 1.0;
+
+/// What a search result points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Post,
+    Book,
+}
+
+impl SearchKind {
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Post => "Article",
+            Self::Book => "Book",
+        }
+    }
+}
+
+/// A piece of a result snippet; `highlighted` pieces are words matching the query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnippetPart {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    pub title: String,
+    pub path: String,
+    /// Publication date of a post, author of a book.
+    pub detail: String,
+    /// Excerpt around the first match, or the start of the text when only the title or
+    /// tags matched.
+    pub snippet: Vec<SnippetPart>,
+    pub score: f64,
+}
+
+/// Ranked results for `query`, best match first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResults {
+    pub query: String,
+    pub hits: Vec<SearchHit>,
+}
+
+/// Path of the search page for `query`, form-encoded like a submitted search form.
+#[must_use]
+pub fn search_path(query: &str) -> String {
+    let mut path = String::from("/search?q=");
+    for byte in query.trim().bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                path.push(char::from(byte));
+            }
+            b' ' => path.push('+'),
+            _ => {
+                let _ = write!(path, "%{byte:02X}");
+            }
+        }
+    }
+    path
+}
+
+/// Compact index of every published post and book served at `/search-index.json`,
+/// searched in the browser as the reader types. Field names are shortened to keep
+/// the download small.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientSearchIndex {
+    #[serde(rename = "v")]
+    pub version: u32,
+    #[serde(rename = "e")]
+    pub entries: Vec<ClientSearchEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientSearchEntry {
+    #[serde(rename = "k")]
+    pub kind: SearchKind,
+    #[serde(rename = "t")]
+    pub title: String,
+    #[serde(rename = "p")]
+    pub path: String,
+    #[serde(rename = "d")]
+    pub detail: String,
+    /// Terms of the tags, and of the author of a book.
+    #[serde(rename = "g", default)]
+    pub tags: Vec<String>,
+    /// The most frequent terms of the text, most frequent first.
+    #[serde(rename = "w", default)]
+    pub words: Vec<String>,
+}
+
+impl ClientSearchIndex {
+    /// Entries matching every term of `query`, best match first. The terms match whole
+    /// words, word prefixes ("asy" finds "async") and words with a typo or two
+    /// ("leptso" finds "leptos"), in that order of preference. The word still being
+    /// typed also counts when it is too short or a stop word to be a term on its own,
+    /// but only as a prefix, so "as" already finds "async".
+    #[must_use]
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&ClientSearchEntry> {
+        let query_terms = terms(query);
+        let partial = query
+            .rsplit(|c: char| !c.is_alphanumeric())
+            .next()
+            .map(str::to_lowercase)
+            .filter(|word| !word.is_empty() && !is_term(word));
+        if query_terms.is_empty() && partial.is_none() {
+            return Vec::new();
+        }
+
+        let mut scored: Vec<(&ClientSearchEntry, f64)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let title_terms = terms(&entry.title);
+                let fields = [
+                    (title_terms.as_slice(), TITLE_WEIGHT),
+                    (entry.tags.as_slice(), TAG_WEIGHT),
+                    (entry.words.as_slice(), WORD_WEIGHT),
+                ];
+                let best_match = |score_term: &dyn Fn(&str) -> f64| {
+                    fields
+                        .iter()
+                        .map(|(field, weight)| {
+                            weight
+                                * field
+                                    .iter()
+                                    .map(|term| score_term(term))
+                                    .fold(0.0, f64::max)
+                        })
+                        .fold(0.0, f64::max)
+                };
+                query_terms
+                    .iter()
+                    .try_fold(0.0, |score, query_term| {
+                        let best = best_match(&|term| similarity(query_term, term));
+                        (best > 0.0).then_some(score + best)
+                    })
+                    .and_then(|score| {
+                        let Some(partial) = &partial else {
+                            return Some(score);
+                        };
+                        let best = best_match(&|term| prefix_similarity(partial, term));
+                        (best > 0.0).then_some(score + best)
+                    })
+                    .map(|score| (entry, score))
+            })
+            .collect();
+        // Stable sort keeps the index order, newest posts first, for equal scores
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(entry, _)| entry)
+            .collect()
+    }
+}
+
+/// Like [`similarity`], but without typo tolerance, for a word that is only a prefix.
+fn prefix_similarity(partial: &str, term: &str) -> f64 {
+    if term == partial {
+        1.0
+    } else if term.starts_with(partial) {
+        0.8
+    } else {
+        0.0
+    }
+}
+
+/// How well `term` matches what the reader typed, from 1.0 for the same word to 0.0.
+fn similarity(query_term: &str, term: &str) -> f64 {
+    if term == query_term {
+        return 1.0;
+    }
+    if term.starts_with(query_term) {
+        return 0.8;
+    }
+
+    let length = query_term.chars().count();
+    // Short words are too easily mistaken for others
+    let allowed_typos = match length {
+        0..=3 => return 0.0,
+        4..=7 => 1,
+        _ => 2,
+    };
+    if edit_distance(query_term, term) <= allowed_typos {
+        return 0.5;
+    }
+    // A typo in a word that is still being typed
+    let term_prefix: String = term.chars().take(length).collect();
+    if edit_distance(query_term, &term_prefix) <= allowed_typos {
+        return 0.4;
+    }
+    0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, tags: &[&str], words: &[&str]) -> ClientSearchEntry {
+        ClientSearchEntry {
+            kind: SearchKind::Post,
+            title: title.to_string(),
+            path: format!("/blog/{}", title.to_lowercase().replace(' ', "-")),
+            detail: String::new(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+            words: words.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn titles(index: &ClientSearchIndex, query: &str) -> Vec<String> {
+        index
+            .search(query, 10)
+            .into_iter()
+            .map(|entry| entry.title.clone())
+            .collect()
+    }
+
+    #[test]
+    fn encodes_the_search_path() {
+        assert_eq!(search_path(" C++ & Rust "), "/search?q=C%2B%2B+%26+Rust");
+        assert_eq!(search_path("häst"), "/search?q=h%C3%A4st");
+    }
+
+    #[test]
+    fn matches_prefixes_and_typos() {
+        let index = ClientSearchIndex {
+            version: CLIENT_INDEX_VERSION,
+            entries: vec![
+                entry("Async Rust", &["rust"], &["futures", "executors"]),
+                entry("Leptos Routing", &["leptos", "rust"], &["params"]),
+            ],
+        };
+
+        assert_eq!(titles(&index, "asy"), vec!["Async Rust"]);
+        assert_eq!(titles(&index, "leptso"), vec!["Leptos Routing"]);
+        assert_eq!(titles(&index, "excutors"), vec!["Async Rust"]);
+        assert!(titles(&index, "python").is_empty());
+    }
+
+    #[test]
+    fn matches_the_word_being_typed_even_if_it_is_a_stop_word() {
+        let index = ClientSearchIndex {
+            version: CLIENT_INDEX_VERSION,
+            entries: vec![
+                entry("Async Rust", &["rust"], &["futures"]),
+                entry("Leptos Routing", &["leptos", "rust"], &["params"]),
+            ],
+        };
+
+        assert_eq!(titles(&index, "as"), vec!["Async Rust"]);
+        assert_eq!(titles(&index, "rust a"), vec!["Async Rust"]);
+        assert_eq!(titles(&index, "l"), vec!["Leptos Routing"]);
+        // A finished stop word is dropped as before
+        assert_eq!(titles(&index, "as "), Vec::<String>::new());
+        assert_eq!(titles(&index, "as rust").len(), 2);
+    }
+
+    #[test]
+    fn prefers_title_matches_and_requires_every_term() {
+        let index = ClientSearchIndex {
+            version: CLIENT_INDEX_VERSION,
+            entries: vec![
+                entry("Testing in practice", &[], &["rust", "async"]),
+                entry("Rust in production", &[], &["deployment"]),
+            ],
+        };
+
+        assert_eq!(
+            titles(&index, "rust"),
+            vec!["Rust in production", "Testing in practice"]
+        );
+        assert_eq!(titles(&index, "rust async"), vec!["Testing in practice"]);
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::blog::BlogPost;
+use crate::utils::slug::slugify;
+
+/// A multi-part post series, e.g. a tutorial split over several posts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Series {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    /// Partition key, shared with the posts of the series.
+    pub author: String,
+    /// Ids of the posts in reading order. Source of truth for `BlogPost::series_position`.
+    #[serde(default)]
+    pub post_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Series {
+    #[must_use]
+    pub fn new(title: String, description: String, author: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4().to_string(),
+            slug: slugify(&title),
+            title,
+            description,
+            author,
+            post_ids: Vec::new(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Path of the series landing page.
+    #[must_use]
+    pub fn path(&self) -> String {
+        format!("/series/{}", self.slug)
+    }
+
+    /// Orders `posts` by `post_ids`, dropping posts that are not part of the series.
+    #[must_use]
+    pub fn order_posts(&self, mut posts: Vec<BlogPost>) -> Vec<BlogPost> {
+        posts.retain(|post| self.post_ids.contains(&post.id));
+        posts.sort_by_key(|post| self.post_ids.iter().position(|id| id == &post.id));
+        posts
+    }
+}
+
+/// One entry in the list of parts shown on a post page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesPart {
+    pub title: String,
+    pub path: String,
+}
+
+/// Where a post sits in its series: the whole table of contents and its own position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesNavigation {
+    pub title: String,
+    pub path: String,
+    /// Published parts in reading order.
+    pub parts: Vec<SeriesPart>,
+    /// Zero-based index of the current post in `parts`.
+    pub current: usize,
+}
+
+impl SeriesNavigation {
+    /// Builds the navigation for `current_id` from the published posts of `series`.
+    /// Returns `None` when the post is not (or no longer) part of the series.
+    #[must_use]
+    pub fn new(series: &Series, posts: Vec<BlogPost>, current_id: &str) -> Option<Self> {
+        let posts = series.order_posts(posts);
+        let current = posts.iter().position(|post| post.id == current_id)?;
+
+        Some(Self {
+            title: series.title.clone(),
+            path: series.path(),
+            parts: posts
+                .iter()
+                .map(|post| SeriesPart {
+                    title: post.title.clone(),
+                    path: post.path(),
+                })
+                .collect(),
+            current,
+        })
+    }
+
+    #[must_use]
+    pub fn previous(&self) -> Option<&SeriesPart> {
+        self.current
+            .checked_sub(1)
+            .and_then(|index| self.parts.get(index))
+    }
+
+    #[must_use]
+    pub fn next(&self) -> Option<&SeriesPart> {
+        self.parts.get(self.current + 1)
+    }
+}
+
+/// A series with its published posts in reading order, for the series landing page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesPosts {
+    pub series: Series,
+    pub posts: Vec<BlogPost>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(id: &str) -> BlogPost {
+        let mut post = BlogPost::new(
+            format!("Part {id}"),
+            String::new(),
+            "Mike".to_string(),
+            Vec::new(),
+            None,
+        );
+        post.id = id.to_string();
+        post
+    }
+
+    fn series(post_ids: &[&str]) -> Series {
+        let mut series = Series::new("Async Rust".to_string(), String::new(), "Mike".to_string());
+        series.post_ids = post_ids.iter().map(|id| (*id).to_string()).collect();
+        series
+    }
+
+    fn ids(posts: &[BlogPost]) -> Vec<&str> {
+        posts.iter().map(|post| post.id.as_str()).collect()
+    }
+
+    #[test]
+    fn orders_posts_by_series_and_drops_strangers() {
+        let series = series(&["a", "b", "c"]);
+
+        let ordered = series.order_posts(vec![post("c"), post("x"), post("a"), post("b")]);
+
+        assert_eq!(ids(&ordered), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn navigates_between_published_parts() {
+        // Part "b" is not published, so it is left out of the navigation
+        let series = series(&["a", "b", "c", "d"]);
+        let posts = || vec![post("d"), post("a"), post("c")];
+
+        let Some(first) = SeriesNavigation::new(&series, posts(), "a") else {
+            panic!("the first part should have navigation");
+        };
+        assert_eq!(first.title, "Async Rust");
+        assert_eq!(first.path, "/series/async-rust");
+        assert_eq!(first.parts.len(), 3);
+        assert_eq!(first.current, 0);
+        assert_eq!(first.previous(), None);
+        assert_eq!(first.next().map(|part| part.title.as_str()), Some("Part c"));
+
+        let Some(middle) = SeriesNavigation::new(&series, posts(), "c") else {
+            panic!("the middle part should have navigation");
+        };
+        assert_eq!(middle.current, 1);
+        assert_eq!(
+            middle.previous().map(|part| part.title.as_str()),
+            Some("Part a")
+        );
+        assert_eq!(
+            middle.next().map(|part| part.title.as_str()),
+            Some("Part d")
+        );
+
+        let Some(last) = SeriesNavigation::new(&series, posts(), "d") else {
+            panic!("the last part should have navigation");
+        };
+        assert_eq!(last.next(), None);
+    }
+
+    #[test]
+    fn posts_outside_the_series_get_no_navigation() {
+        let series = series(&["a", "b"]);
+
+        // Dropped from the series, or not published and therefore not among the posts
+        assert!(SeriesNavigation::new(&series, vec![post("a"), post("x")], "x").is_none());
+        assert!(SeriesNavigation::new(&series, vec![post("a")], "b").is_none());
+    }
+}
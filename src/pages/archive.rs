@@ -0,0 +1,304 @@
+use chrono::Datelike;
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::api::blogs::{get_archive_posts_server, get_archive_server};
+use crate::models::archive::{ArchivePeriod, ArchiveYear};
+use crate::models::blog::BlogPost;
+use crate::utils::date_formatter::{format_date_iso, format_date_short, format_month_year};
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn ArchivePage() -> impl IntoView {
+    let archive_resource = Resource::new_blocking(|| (), |()| get_archive_server());
+
+    view! {
+        <Title text="Archive - Mike's Dev Blog" />
+        <Meta
+            name="description"
+            content="Every article on Mike's Dev Blog by year and month of publication."
+        />
+
+        <div class="bg-gradient-to-b from-blue-50 to-white">
+            <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16 text-center">
+                <h1 class="text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl">
+                    <span class="block bg-gradient-to-r from-blue-600 to-purple-600 bg-clip-text text-transparent">
+                        "Archive"
+                    </span>
+                </h1>
+                <p class="max-w-xl mt-5 mx-auto text-xl text-gray-500">
+                    "Everything I have published, month by month."
+                </p>
+            </div>
+        </div>
+
+        <div class="max-w-5xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
+            <Suspense fallback=move || {
+                view! { <p class="text-center text-lg text-gray-600">"Loading archive..."</p> }
+            }>
+                {move || {
+                    archive_resource
+                        .get()
+                        .map(|result| match result {
+                            Ok(years) if years.is_empty() => {
+                                view! {
+                                    <p class="text-center text-lg text-gray-500">
+                                        "Nothing published yet."
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                            Ok(years) => {
+                                view! {
+                                    <div class="space-y-12">
+                                        {years
+                                            .into_iter()
+                                            .map(|year| view! { <ArchiveYearSection year=year /> })
+                                            .collect_view()}
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Err(err) => {
+                                view! {
+                                    <p class="text-center text-red-600">
+                                        {format!("Failed to load archive: {err}")}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+fn ArchiveYearSection(year: ArchiveYear) -> impl IntoView {
+    view! {
+        <section>
+            <h2 class="flex items-baseline justify-between border-b border-gray-200 pb-3 mb-6">
+                <A
+                    href=year.period.path()
+                    attr:class="text-3xl font-bold text-gray-900 hover:text-blue-600 transition-colors"
+                >
+                    {year.period.label()}
+                </A>
+                <span class="text-sm text-gray-500">{post_count(year.count)}</span>
+            </h2>
+            <ul class="grid gap-4 grid-cols-2 sm:grid-cols-3 md:grid-cols-4">
+                {year
+                    .months
+                    .into_iter()
+                    .map(|month| {
+                        view! {
+                            <li>
+                                <A
+                                    href=month.period.path()
+                                    attr:class="flex items-center justify-between px-4 py-3 rounded-lg bg-white border border-gray-200 hover:border-blue-300 hover:bg-blue-50 transition-colors"
+                                >
+                                    <span class="font-medium text-gray-900">
+                                        {month.period.label()}
+                                    </span>
+                                    <span class="ml-2 inline-flex items-center justify-center px-2 py-0.5 rounded-full text-xs font-semibold bg-blue-100 text-blue-700">
+                                        {month.count}
+                                    </span>
+                                </A>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ul>
+        </section>
+    }
+}
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn ArchivePeriodPage() -> impl IntoView {
+    let params = use_params_map();
+    let period_params = move || params.with(|params| (params.get("year"), params.get("month")));
+
+    // Blocking so the title and status code are part of the SSR response
+    let posts_resource = Resource::new_blocking(period_params, |(year, month)| async move {
+        let Some(period) = ArchivePeriod::parse(&year.unwrap_or_default(), month.as_deref()) else {
+            return Err("Not a valid archive date".to_string());
+        };
+        get_archive_posts_server(period)
+            .await
+            .map_err(|e| e.to_string())
+    });
+
+    view! {
+        <Suspense fallback=move || {
+            view! { <p class="text-center text-lg text-gray-600 py-16">"Loading posts..."</p> }
+        }>
+            {move || {
+                posts_resource
+                    .get()
+                    .map(|result| match result {
+                        Ok(archive) => {
+                            let period = archive.period;
+                            let count = archive.posts.len();
+                            // A year lists its posts under month headings
+                            let sections = if period.month.is_some() {
+                                vec![(None, archive.posts)]
+                            } else {
+                                group_by_month(archive.posts)
+                            };
+                            view! {
+                                <Title text=format!("{} - Archive - Mike's Dev Blog", period.label()) />
+                                <Meta
+                                    name="description"
+                                    content=format!(
+                                        "{} published on Mike's Dev Blog in {}.",
+                                        post_count(count),
+                                        period.label(),
+                                    )
+                                />
+
+                                <div class="bg-gradient-to-b from-blue-50 to-white">
+                                    <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16 text-center">
+                                        <nav class="text-sm font-semibold uppercase tracking-wide text-blue-600 space-x-2">
+                                            <A href="/blog/archive" attr:class="hover:text-blue-800">
+                                                "Archive"
+                                            </A>
+                                            {period
+                                                .month
+                                                .map(|_| {
+                                                    let year = period.year_period();
+                                                    view! {
+                                                        <span aria-hidden="true">"/"</span>
+                                                        <A href=year.path() attr:class="hover:text-blue-800">
+                                                            {year.label()}
+                                                        </A>
+                                                    }
+                                                })}
+                                        </nav>
+                                        <h1 class="mt-2 text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl">
+                                            {period.label()}
+                                        </h1>
+                                        <p class="max-w-xl mt-5 mx-auto text-xl text-gray-500">
+                                            {post_count(count)}
+                                        </p>
+                                    </div>
+                                </div>
+
+                                <div class="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-12 space-y-10">
+                                    {sections
+                                        .into_iter()
+                                        .map(|(heading, posts)| {
+                                            view! {
+                                                <section>
+                                                    {heading
+                                                        .map(|(label, path)| {
+                                                            view! {
+                                                                <h2 class="text-2xl font-bold text-gray-900 border-b border-gray-200 pb-2 mb-4">
+                                                                    <A href=path attr:class="hover:text-blue-600">
+                                                                        {label}
+                                                                    </A>
+                                                                </h2>
+                                                            }
+                                                        })}
+                                                    <ul class="divide-y divide-gray-100">
+                                                        {posts
+                                                            .into_iter()
+                                                            .map(|post| view! { <ArchivePostRow post=post /> })
+                                                            .collect_view()}
+                                                    </ul>
+                                                </section>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        Err(err) => {
+                            #[cfg(feature = "ssr")]
+                            {
+                                let response = expect_context::<leptos_axum::ResponseOptions>();
+                                response.set_status(http::StatusCode::NOT_FOUND);
+                            }
+                            view! {
+                                <Title text="Archive Not Found - Mike's Dev Blog" />
+                                <div class="max-w-4xl mx-auto px-6 py-16 text-center">
+                                    <h1 class="text-2xl font-bold text-gray-900 mb-4">
+                                        "Nothing Here"
+                                    </h1>
+                                    <p class="text-gray-600 mb-8">{err}</p>
+                                    <A
+                                        href="/blog/archive"
+                                        attr:class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors"
+                                    >
+                                        "Browse the archive"
+                                    </A>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
+
+#[component]
+fn ArchivePostRow(post: BlogPost) -> impl IntoView {
+    let stats = post.stats();
+
+    view! {
+        <li class="py-4 flex flex-col sm:flex-row sm:items-baseline gap-1 sm:gap-6">
+            <time
+                datetime=format_date_iso(post.created_at)
+                class="w-32 shrink-0 text-sm text-gray-500"
+            >
+                {format_date_short(post.created_at)}
+            </time>
+            <div>
+                <A
+                    href=post.path()
+                    attr:class="text-lg font-semibold text-gray-900 hover:text-blue-600 transition-colors"
+                >
+                    {post.title.clone()}
+                </A>
+                <span class="ml-2 text-sm text-gray-500">
+                    {format!("{} min read", stats.reading_time_minutes)}
+                </span>
+            </div>
+        </li>
+    }
+}
+
+/// Splits posts sorted by date into one section per month, each headed by the month's
+/// name and archive path.
+fn group_by_month(posts: Vec<BlogPost>) -> Vec<(Option<(String, String)>, Vec<BlogPost>)> {
+    let mut sections: Vec<(Option<(String, String)>, Vec<BlogPost>)> = Vec::new();
+    let mut current_month = None;
+    for post in posts {
+        let month = (post.created_at.year(), post.created_at.month());
+        if current_month != Some(month) {
+            current_month = Some(month);
+            let period = ArchivePeriod {
+                year: month.0,
+                month: Some(month.1),
+            };
+            sections.push((
+                Some((format_month_year(post.created_at), period.path())),
+                Vec::new(),
+            ));
+        }
+        if let Some((_, section)) = sections.last_mut() {
+            section.push(post);
+        }
+    }
+    sections
+}
+
+fn post_count(count: usize) -> String {
+    format!("{count} post{}", if count == 1 { "" } else { "s" })
+}
@@ -1,13 +1,11 @@
 // filepath: /home/mikko/dev/mike-dev-blog/src/pages/blog.rs
 use crate::components::blog_list::BlogList;
 use leptos::prelude::*;
-use leptos_meta::Title;
+use leptos_router::components::A;
 #[component]
 #[allow(clippy::must_use_candidate)]
 pub fn BlogPage() -> impl IntoView {
     view! {
-        <Title text="Blog - Mike's Dev Blog" />
-
         <div class="bg-gradient-to-b from-blue-50 to-white">
             <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16">
                 <div class="text-center">
@@ -20,6 +18,20 @@ pub fn BlogPage() -> impl IntoView {
                     <p class="max-w-xl mt-5 mx-auto text-xl text-gray-500">
                         "Exploring ideas, technologies, and best practices in modern software development."
                     </p>
+                    <div class="mt-6 space-x-6">
+                        <A
+                            href="/blog/tags"
+                            attr:class="text-blue-600 hover:text-blue-800 font-medium"
+                        >
+                            "Browse by tag"
+                        </A>
+                        <A
+                            href="/blog/archive"
+                            attr:class="text-blue-600 hover:text-blue-800 font-medium"
+                        >
+                            "Browse the archive"
+                        </A>
+                    </div>
                 </div>
             </div>
         </div>
@@ -14,58 +14,8 @@ pub fn BooksPage() -> impl IntoView {
     let (selected_category, set_selected_category) = signal(None::<BookCategory>);
     let (search_query, set_search_query) = signal(String::new());
 
-    // Action to fetch books
-    let fetch_books_action = Action::new(|(): &()| async move { get_books_server().await });
-
-    // Trigger initial fetch
-    Effect::new(move |_| {
-        fetch_books_action.dispatch(());
-    });
-
-    // Filtered books based on category and search
-    let filtered_books = Memo::new(move |_| {
-        if let Some(Ok(books)) = fetch_books_action.value().get() {
-            let mut filtered: Vec<Book> = books;
-
-            // Filter by category
-            if let Some(category) = selected_category.get() {
-                filtered.retain(|book| book.category == category);
-            }
-
-            // Filter by search query
-            let query = search_query.get().to_lowercase();
-            if !query.is_empty() {
-                filtered.retain(|book| {
-                    book.title.to_lowercase().contains(&query)
-                        || book.author.to_lowercase().contains(&query)
-                        || book.description.to_lowercase().contains(&query)
-                        || book
-                            .tags
-                            .iter()
-                            .any(|tag| tag.to_lowercase().contains(&query))
-                });
-            }
-
-            // Group by category for better display
-            let mut grouped: HashMap<BookCategory, Vec<Book>> = HashMap::new();
-            for book in filtered {
-                grouped.entry(book.category.clone()).or_default().push(book);
-            }
-
-            // Sort books within each category by rating (highest first)
-            for books in grouped.values_mut() {
-                books.sort_by(|a, b| {
-                    b.rating
-                        .partial_cmp(&a.rating)
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                });
-            }
-
-            grouped
-        } else {
-            HashMap::new()
-        }
-    });
+    // Blocking so the recommendations are part of the server-rendered HTML
+    let books_resource = Resource::new_blocking(|| (), |()| get_books_server());
 
     view! {
         <Title text="Book Recommendations - Mike's Dev Blog" />
@@ -176,117 +126,161 @@ pub fn BooksPage() -> impl IntoView {
                 </div>
             </div>
 
-            // Loading State
-            <Show when=move || fetch_books_action.pending().get()>
-                <div class="text-center py-12">
-                    <div class="inline-flex items-center">
-                        <svg
-                            class="animate-spin -ml-1 mr-3 h-8 w-8 text-blue-600"
-                            xmlns="http://www.w3.org/2000/svg"
-                            fill="none"
-                            viewBox="0 0 24 24"
-                        >
-                            <circle
-                                class="opacity-25"
-                                cx="12"
-                                cy="12"
-                                r="10"
-                                stroke="currentColor"
-                                stroke-width="4"
-                            ></circle>
-                            <path
-                                class="opacity-75"
-                                fill="currentColor"
-                                d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"
-                            ></path>
-                        </svg>
-                        <span class="text-xl text-gray-600">"Loading book recommendations..."</span>
+            <Suspense fallback=move || {
+                view! {
+                    <div class="text-center py-12">
+                        <div class="inline-flex items-center">
+                            <svg
+                                class="animate-spin -ml-1 mr-3 h-8 w-8 text-blue-600"
+                                xmlns="http://www.w3.org/2000/svg"
+                                fill="none"
+                                viewBox="0 0 24 24"
+                            >
+                                <circle
+                                    class="opacity-25"
+                                    cx="12"
+                                    cy="12"
+                                    r="10"
+                                    stroke="currentColor"
+                                    stroke-width="4"
+                                ></circle>
+                                <path
+                                    class="opacity-75"
+                                    fill="currentColor"
+                                    d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"
+                                ></path>
+                            </svg>
+                            <span class="text-xl text-gray-600">"Loading book recommendations..."</span>
+                        </div>
                     </div>
-                </div>
-            </Show>
-
-            <Show when=move || {
-                !fetch_books_action.pending().get() && fetch_books_action.value().get().is_some()
+                }
             }>
                 {move || {
-                    match fetch_books_action.value().get() {
-                        Some(Ok(_)) => {
-                            let grouped_books = filtered_books.get();
-                            if grouped_books.is_empty() {
-                                view! {
-                                    <div class="text-center py-16">
-                                        <svg
-                                            class="mx-auto h-12 w-12 text-gray-400"
-                                            fill="none"
-                                            viewBox="0 0 24 24"
-                                            stroke="currentColor"
-                                        >
-                                            <path
-                                                stroke-linecap="round"
-                                                stroke-linejoin="round"
-                                                stroke-width="2"
-                                                d="M12 6.253v13m0-13C10.832 5.477 9.246 5 7.5 5S4.168 5.477 3 6.253v13C4.168 18.477 5.754 18 7.5 18s3.332.477 4.5 1.253m0-13C13.168 5.477 14.754 5 16.5 5c1.746 0 3.332.477 4.5 1.253v13C19.832 18.477 18.246 18 16.5 18c-1.746 0-3.332.477-4.5 1.253"
-                                            />
-                                        </svg>
-                                        <h3 class="mt-2 text-2xl font-medium text-gray-900">
-                                            "No books found"
-                                        </h3>
-                                        <p class="mt-1 text-lg text-gray-500">
-                                            "Try adjusting your search or category filter."
-                                        </p>
-                                    </div>
+                    books_resource
+                        .get()
+                        .map(|result| match result {
+                            Ok(books) => {
+                                let grouped_books = group_books(
+                                    books,
+                                    selected_category.get().as_ref(),
+                                    &search_query.get(),
+                                );
+                                if grouped_books.is_empty() {
+                                    view! {
+                                        <div class="text-center py-16">
+                                            <svg
+                                                class="mx-auto h-12 w-12 text-gray-400"
+                                                fill="none"
+                                                viewBox="0 0 24 24"
+                                                stroke="currentColor"
+                                            >
+                                                <path
+                                                    stroke-linecap="round"
+                                                    stroke-linejoin="round"
+                                                    stroke-width="2"
+                                                    d="M12 6.253v13m0-13C10.832 5.477 9.246 5 7.5 5S4.168 5.477 3 6.253v13C4.168 18.477 5.754 18 7.5 18s3.332.477 4.5 1.253m0-13C13.168 5.477 14.754 5 16.5 5c1.746 0 3.332.477 4.5 1.253v13C19.832 18.477 18.246 18 16.5 18c-1.746 0-3.332.477-4.5 1.253"
+                                                />
+                                            </svg>
+                                            <h3 class="mt-2 text-2xl font-medium text-gray-900">
+                                                "No books found"
+                                            </h3>
+                                            <p class="mt-1 text-lg text-gray-500">
+                                                "Try adjusting your search or category filter."
+                                            </p>
+                                        </div>
+                                    }
+                                        .into_any()
+                                } else {
+                                    view! {
+                                        <div class="space-y-12">
+                                            {grouped_books
+                                                .into_iter()
+                                                .map(|(category, books)| {
+                                                    view! {
+                                                        <BookCategorySection category=category books=books />
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </div>
+                                    }
+                                        .into_any()
                                 }
-                            } else {
+                            }
+                            Err(err) => {
                                 view! {
-                                    <div class="space-y-12">
-                                        {grouped_books
-                                            .into_iter()
-                                            .map(|(category, books)| {
-                                                view! {
-                                                    <BookCategorySection category=category books=books />
+                                    <div class="text-center py-12">
+                                        <div class="bg-red-50 border-l-4 border-red-400 p-6 rounded-md max-w-lg mx-auto">
+                                            <h3 class="text-lg font-medium text-red-800">
+                                                "Error Loading Books"
+                                            </h3>
+                                            <p class="mt-2 text-sm text-red-700">
+                                                {format!("Failed to load book recommendations: {err}")}
+                                            </p>
+                                            <button
+                                                class="mt-4 inline-flex items-center px-4 py-2 border border-transparent text-sm font-medium rounded-md text-red-700 bg-red-100 hover:bg-red-200"
+                                                on:click=move |_| {
+                                                    books_resource.refetch();
                                                 }
-                                            })
-                                            .collect_view()}
+                                            >
+                                                "Try Again"
+                                            </button>
+                                        </div>
                                     </div>
                                 }
+                                    .into_any()
                             }
-                        }
-                        Some(Err(err)) => {
-                            view! {
-                                <div class="text-center py-12">
-                                    <div class="bg-red-50 border-l-4 border-red-400 p-6 rounded-md max-w-lg mx-auto">
-                                        <h3 class="text-lg font-medium text-red-800">
-                                            "Error Loading Books"
-                                        </h3>
-                                        <p class="mt-2 text-sm text-red-700">
-                                            {format!("Failed to load book recommendations: {err}")}
-                                        </p>
-                                        <button
-                                            class="mt-4 inline-flex items-center px-4 py-2 border border-transparent text-sm font-medium rounded-md text-red-700 bg-red-100 hover:bg-red-200"
-                                            on:click=move |_| {
-                                                fetch_books_action.dispatch(());
-                                            }
-                                        >
-                                            "Try Again"
-                                        </button>
-                                    </div>
-                                </div>
-                            }
-                        }
-                        None => {
-                            view! {
-                                <div class="text-center py-12">
-                                    <p class="text-xl text-gray-600">"Initializing..."</p>
-                                </div>
-                            }
-                        }
-                    }
+                        })
                 }}
-            </Show>
+            </Suspense>
         </div>
     }
 }
 
+/// Books matching `category` and `query`, grouped by category and sorted by rating.
+fn group_books(
+    books: Vec<Book>,
+    category: Option<&BookCategory>,
+    query: &str,
+) -> HashMap<BookCategory, Vec<Book>> {
+    let mut filtered: Vec<Book> = books;
+
+    // Filter by category
+    if let Some(category) = category {
+        filtered.retain(|book| &book.category == category);
+    }
+
+    // Filter by search query
+    let query = query.to_lowercase();
+    if !query.is_empty() {
+        filtered.retain(|book| {
+            book.title.to_lowercase().contains(&query)
+                || book.author.to_lowercase().contains(&query)
+                || book.description.to_lowercase().contains(&query)
+                || book
+                    .tags
+                    .iter()
+                    .any(|tag| tag.to_lowercase().contains(&query))
+        });
+    }
+
+    // Group by category for better display
+    let mut grouped: HashMap<BookCategory, Vec<Book>> = HashMap::new();
+    for book in filtered {
+        grouped.entry(book.category.clone()).or_default().push(book);
+    }
+
+    // Sort books within each category by rating (highest first)
+    for books in grouped.values_mut() {
+        books.sort_by(|a, b| {
+            b.rating
+                .partial_cmp(&a.rating)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    grouped
+}
+
 #[component]
 fn BookCategorySection(category: BookCategory, books: Vec<Book>) -> impl IntoView {
     view! {
@@ -352,7 +346,10 @@ fn BookCard(book: Book) -> impl IntoView {
     }).collect_view();
 
     view! {
-        <article class="bg-white rounded-xl shadow-sm border border-gray-200 overflow-hidden hover:shadow-lg transition-all duration-300 transform hover:-translate-y-1">
+        <article
+            id=book.anchor()
+            class="bg-white rounded-xl shadow-sm border border-gray-200 overflow-hidden hover:shadow-lg transition-all duration-300 transform hover:-translate-y-1 scroll-mt-24"
+        >
             <div class="p-6">
                 // Book cover and basic info
                 <div class="flex space-x-4 mb-4">
@@ -1,4 +1,8 @@
 pub mod about;
+pub mod archive;
 pub mod blog;
 pub mod book;
 pub mod home;
+pub mod search;
+pub mod series;
+pub mod tags;
@@ -0,0 +1,170 @@
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::{Form, A};
+use leptos_router::hooks::use_query_map;
+
+use crate::api::search::search_server;
+use crate::models::search::{SearchHit, SearchKind};
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn SearchPage() -> impl IntoView {
+    let query_map = use_query_map();
+    let query = move || query_map.with(|params| params.get("q").unwrap_or_default());
+
+    // Blocking so results are part of the server-rendered page and links to it work
+    // without JavaScript
+    let results_resource = Resource::new_blocking(query, search_server);
+
+    view! {
+        <Title text=move || {
+            let query = query();
+            if query.trim().is_empty() {
+                "Search - Mike's Dev Blog".to_string()
+            } else {
+                format!("Search: {} - Mike's Dev Blog", query.trim())
+            }
+        } />
+        // Result pages are endless variations of the same content
+        <Meta name="robots" content="noindex, follow" />
+
+        <div class="bg-gradient-to-b from-blue-50 to-white">
+            <div class="max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-16">
+                <h1 class="text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl text-center">
+                    <span class="block bg-gradient-to-r from-blue-600 to-purple-600 bg-clip-text text-transparent">
+                        "Search"
+                    </span>
+                </h1>
+                <Form method="GET" action="/search">
+                    <div class="mt-8 flex gap-3">
+                        <label for="search-query" class="sr-only">
+                            "Search articles and books"
+                        </label>
+                        <input
+                            id="search-query"
+                            type="search"
+                            name="q"
+                            prop:value=query
+                            placeholder="Search articles and books..."
+                            class="flex-1 px-4 py-3 border border-gray-300 rounded-lg focus:ring-2 focus:ring-blue-500 focus:border-transparent"
+                        />
+                        <button
+                            type="submit"
+                            class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-lg text-white bg-blue-600 hover:bg-blue-700 transition-colors"
+                        >
+                            "Search"
+                        </button>
+                    </div>
+                </Form>
+            </div>
+        </div>
+
+        <div class="max-w-3xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
+            <Transition fallback=move || {
+                view! { <p class="text-center text-lg text-gray-600">"Searching..."</p> }
+            }>
+                {move || {
+                    results_resource
+                        .get()
+                        .map(|result| match result {
+                            Ok(results) if results.query.is_empty() => {
+                                view! {
+                                    <p class="text-center text-lg text-gray-500">
+                                        "Search titles, content and tags of every article, and the books I recommend."
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                            Ok(results) if results.hits.is_empty() => {
+                                view! {
+                                    <div class="text-center">
+                                        <p class="text-lg text-gray-700">
+                                            {format!("No results for \"{}\".", results.query)}
+                                        </p>
+                                        <p class="mt-2 text-gray-500">
+                                            "Try fewer or different words, or "
+                                            <A
+                                                href="/blog/tags"
+                                                attr:class="text-blue-600 hover:text-blue-800 font-medium"
+                                            >
+                                                "browse by tag"
+                                            </A>
+                                            "."
+                                        </p>
+                                    </div>
+                                }
+                                    .into_any()
+                            }
+                            Ok(results) => {
+                                let count = results.hits.len();
+                                view! {
+                                    <p class="text-sm text-gray-500 mb-6">
+                                        {format!(
+                                            "{count} result{} for \"{}\"",
+                                            if count == 1 { "" } else { "s" },
+                                            results.query,
+                                        )}
+                                    </p>
+                                    <ol class="space-y-8">
+                                        {results
+                                            .hits
+                                            .into_iter()
+                                            .map(|hit| view! { <SearchResult hit=hit /> })
+                                            .collect_view()}
+                                    </ol>
+                                }
+                                    .into_any()
+                            }
+                            Err(err) => {
+                                view! {
+                                    <p class="text-center text-red-600">
+                                        {format!("Search failed: {err}")}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn SearchResult(hit: SearchHit) -> impl IntoView {
+    let badge_class = match hit.kind {
+        SearchKind::Post => "bg-blue-100 text-blue-800",
+        SearchKind::Book => "bg-green-100 text-green-800",
+    };
+
+    view! {
+        <li>
+            <div class="flex items-center gap-2 text-sm text-gray-500">
+                <span class=format!(
+                    "inline-flex items-center px-2.5 py-0.5 rounded-full text-xs font-medium {badge_class}",
+                )>{hit.kind.label()}</span>
+                <span>{hit.detail}</span>
+            </div>
+            <h2 class="mt-1 text-xl font-semibold">
+                <A href=hit.path attr:class="text-gray-900 hover:text-blue-600 transition-colors">
+                    {hit.title}
+                </A>
+            </h2>
+            <p class="mt-1 text-gray-600 leading-relaxed">
+                {hit
+                    .snippet
+                    .into_iter()
+                    .map(|part| {
+                        if part.highlighted {
+                            view! { <mark class="bg-yellow-100 text-gray-900 rounded px-0.5">{part.text}</mark> }
+                                .into_any()
+                        } else {
+                            part.text.into_any()
+                        }
+                    })
+                    .collect_view()}
+            </p>
+        </li>
+    }
+}
@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::api::blogs::get_series_posts_server;
+use crate::components::blog_list::BlogPostCard;
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn SeriesPage() -> impl IntoView {
+    let params = use_params_map();
+    let series_slug = move || params.with(|params| params.get("slug").unwrap_or_default());
+
+    // Blocking so the title, meta description and status code are part of the SSR response
+    let series_resource = Resource::new_blocking(series_slug, get_series_posts_server);
+
+    view! {
+        <Suspense fallback=move || {
+            view! { <p class="text-center text-lg text-gray-600 py-16">"Loading series..."</p> }
+        }>
+            {move || {
+                series_resource
+                    .get()
+                    .map(|result| match result {
+                        Ok(series_posts) => {
+                            let series = series_posts.series;
+                            let count = series_posts.posts.len();
+                            view! {
+                                <Title text=format!("{} - Mike's Dev Blog", series.title) />
+                                <Meta name="description" content=series.description.clone() />
+
+                                <div class="bg-gradient-to-b from-blue-50 to-white">
+                                    <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16 text-center">
+                                        <p class="text-sm font-semibold uppercase tracking-wide text-blue-600">
+                                            "Series"
+                                        </p>
+                                        <h1 class="mt-2 text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl">
+                                            {series.title.clone()}
+                                        </h1>
+                                        <p class="max-w-2xl mt-5 mx-auto text-xl text-gray-500">
+                                            {series.description.clone()}
+                                        </p>
+                                        <p class="mt-4 text-sm text-gray-500">
+                                            {format!("{count} part{}", if count == 1 { "" } else { "s" })}
+                                        </p>
+                                    </div>
+                                </div>
+
+                                <div class="max-w-4xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
+                                    <ol class="space-y-10">
+                                        {series_posts
+                                            .posts
+                                            .into_iter()
+                                            .enumerate()
+                                            .map(|(index, post)| {
+                                                view! {
+                                                    <li>
+                                                        <p class="mb-3 text-sm font-semibold uppercase tracking-wide text-blue-600">
+                                                            {format!("Part {}", index + 1)}
+                                                        </p>
+                                                        <BlogPostCard post=post />
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ol>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        Err(err) => {
+                            #[cfg(feature = "ssr")]
+                            {
+                                let response = expect_context::<leptos_axum::ResponseOptions>();
+                                response.set_status(http::StatusCode::NOT_FOUND);
+                            }
+                            view! {
+                                <Title text="Series Not Found - Mike's Dev Blog" />
+                                <div class="max-w-4xl mx-auto px-6 py-16 text-center">
+                                    <h1 class="text-2xl font-bold text-gray-900 mb-4">
+                                        "Series Not Found"
+                                    </h1>
+                                    <p class="text-gray-600 mb-8">{err.to_string()}</p>
+                                    <A
+                                        href="/blog"
+                                        attr:class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors"
+                                    >
+                                        "Back to Blog"
+                                    </A>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,184 @@
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::api::blogs::{get_tag_posts_server, get_tags_server};
+use crate::components::blog_list::BlogPostCard;
+use crate::models::blog::tag_slug_path;
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+pub fn TagsPage() -> impl IntoView {
+    let tags_resource = Resource::new_blocking(|| (), |()| get_tags_server());
+
+    view! {
+        <Title text="Tags - Mike's Dev Blog" />
+        <Meta
+            name="description"
+            content="Browse all topics covered on Mike's Dev Blog, from Rust and Python to software architecture and cloud."
+        />
+
+        <div class="bg-gradient-to-b from-blue-50 to-white">
+            <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16 text-center">
+                <h1 class="text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl">
+                    <span class="block bg-gradient-to-r from-blue-600 to-purple-600 bg-clip-text text-transparent">
+                        "Browse by Tag"
+                    </span>
+                </h1>
+                <p class="max-w-xl mt-5 mx-auto text-xl text-gray-500">
+                    "Every topic I have written about, with the number of posts for each."
+                </p>
+            </div>
+        </div>
+
+        <div class="max-w-5xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
+            <Suspense fallback=move || {
+                view! { <p class="text-center text-lg text-gray-600">"Loading tags..."</p> }
+            }>
+                {move || {
+                    tags_resource
+                        .get()
+                        .map(|result| match result {
+                            Ok(tags) if tags.is_empty() => {
+                                view! {
+                                    <p class="text-center text-lg text-gray-500">"No tags yet."</p>
+                                }
+                                    .into_any()
+                            }
+                            Ok(tags) => {
+                                view! {
+                                    <ul class="flex flex-wrap justify-center gap-4">
+                                        {tags
+                                            .into_iter()
+                                            .map(|tag| {
+                                                view! {
+                                                    <li>
+                                                        <A
+                                                            href=tag_slug_path(&tag.slug)
+                                                            attr:class="inline-flex items-center px-4 py-2 rounded-full text-base font-medium bg-blue-100 text-blue-800 hover:bg-blue-200 transition-colors"
+                                                        >
+                                                            {tag.tag}
+                                                            <span class="ml-2 inline-flex items-center justify-center px-2 py-0.5 rounded-full text-xs font-semibold bg-white text-blue-700">
+                                                                {tag.count}
+                                                            </span>
+                                                        </A>
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                                    .into_any()
+                            }
+                            Err(err) => {
+                                view! {
+                                    <p class="text-center text-red-600">
+                                        {format!("Failed to load tags: {err}")}
+                                    </p>
+                                }
+                                    .into_any()
+                            }
+                        })
+                }}
+            </Suspense>
+        </div>
+    }
+}
+
+#[component]
+#[allow(clippy::must_use_candidate)]
+#[allow(clippy::too_many_lines)]
+pub fn TagPage() -> impl IntoView {
+    let params = use_params_map();
+    let tag_slug = move || params.with(|params| params.get("tag").unwrap_or_default());
+
+    // Blocking so the title, meta description and status code are part of the SSR response
+    let tag_resource = Resource::new_blocking(tag_slug, get_tag_posts_server);
+
+    view! {
+        <Suspense fallback=move || {
+            view! { <p class="text-center text-lg text-gray-600 py-16">"Loading posts..."</p> }
+        }>
+            {move || {
+                tag_resource
+                    .get()
+                    .map(|result| match result {
+                        Ok(tag_posts) => {
+                            let count = tag_posts.posts.len();
+                            view! {
+                                <Title text=format!("Posts tagged \"{}\" - Mike's Dev Blog", tag_posts.tag) />
+                                <Meta
+                                    name="description"
+                                    content=format!(
+                                        "{count} article{} about {} on Mike's Dev Blog.",
+                                        if count == 1 { "" } else { "s" },
+                                        tag_posts.tag,
+                                    )
+                                />
+
+                                <div class="bg-gradient-to-b from-blue-50 to-white">
+                                    <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-16 text-center">
+                                        <p class="text-sm font-semibold uppercase tracking-wide text-blue-600">
+                                            "Tag"
+                                        </p>
+                                        <h1 class="mt-2 text-4xl font-extrabold tracking-tight text-gray-900 sm:text-5xl">
+                                            {tag_posts.tag.clone()}
+                                        </h1>
+                                        <p class="max-w-xl mt-5 mx-auto text-xl text-gray-500">
+                                            {format!(
+                                                "{count} published post{}",
+                                                if count == 1 { "" } else { "s" },
+                                            )}
+                                        </p>
+                                        <div class="mt-6">
+                                            <A
+                                                href="/blog/tags"
+                                                attr:class="text-blue-600 hover:text-blue-800 font-medium"
+                                            >
+                                                "All tags"
+                                            </A>
+                                        </div>
+                                    </div>
+                                </div>
+
+                                <div class="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-12">
+                                    <div class="grid gap-8 md:grid-cols-2 lg:grid-cols-3">
+                                        {tag_posts
+                                            .posts
+                                            .into_iter()
+                                            .map(|post| view! { <BlogPostCard post=post /> })
+                                            .collect_view()}
+                                    </div>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        Err(err) => {
+                            #[cfg(feature = "ssr")]
+                            {
+                                let response = expect_context::<leptos_axum::ResponseOptions>();
+                                response.set_status(http::StatusCode::NOT_FOUND);
+                            }
+                            view! {
+                                <Title text="Tag Not Found - Mike's Dev Blog" />
+                                <div class="max-w-4xl mx-auto px-6 py-16 text-center">
+                                    <h1 class="text-2xl font-bold text-gray-900 mb-4">
+                                        "Tag Not Found"
+                                    </h1>
+                                    <p class="text-gray-600 mb-8">{err.to_string()}</p>
+                                    <A
+                                        href="/blog/tags"
+                                        attr:class="inline-flex items-center px-6 py-3 border border-transparent text-base font-medium rounded-md text-white bg-blue-600 hover:bg-blue-700 transition-colors"
+                                    >
+                                        "Browse all tags"
+                                    </A>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                    })
+            }}
+        </Suspense>
+    }
+}
@@ -0,0 +1,107 @@
+//! GitHub style autolinking of bare `http://`, `https://` and `www.` URLs in text.
+
+use pulldown_cmark::{CowStr, Event, LinkType, Tag, TagEnd};
+
+const PREFIXES: [&str; 3] = ["https://", "http://", "www."];
+
+/// Pushes `text` to `output`, with every bare URL in it turned into a link.
+pub(super) fn push_autolinked<'a>(output: &mut Vec<Event<'a>>, text: CowStr<'a>) {
+    let mut rest: &str = &text;
+    let mut pieces = Vec::new();
+
+    while let Some((start, end)) = find_url(rest) {
+        if start > 0 {
+            pieces.push(Piece::Text(rest[..start].to_string()));
+        }
+        pieces.push(Piece::Url(rest[start..end].to_string()));
+        rest = &rest[end..];
+    }
+
+    if pieces.is_empty() {
+        output.push(Event::Text(text));
+        return;
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Text(rest.to_string()));
+    }
+
+    for piece in pieces {
+        match piece {
+            Piece::Text(text) => output.push(Event::Text(CowStr::from(text))),
+            Piece::Url(url) => {
+                let href = if url.starts_with("www.") {
+                    format!("http://{url}")
+                } else {
+                    url.clone()
+                };
+                output.push(Event::Start(Tag::Link {
+                    link_type: LinkType::Autolink,
+                    dest_url: CowStr::from(href),
+                    title: CowStr::Borrowed(""),
+                    id: CowStr::Borrowed(""),
+                }));
+                output.push(Event::Text(CowStr::from(url)));
+                output.push(Event::End(TagEnd::Link));
+            }
+        }
+    }
+}
+
+enum Piece {
+    Text(String),
+    Url(String),
+}
+
+/// Byte range of the first URL in `text` that starts at a word boundary.
+fn find_url(text: &str) -> Option<(usize, usize)> {
+    let mut search_from = 0;
+
+    loop {
+        let (start, prefix) = PREFIXES
+            .iter()
+            .filter_map(|prefix| {
+                text[search_from..]
+                    .find(prefix)
+                    .map(|index| (search_from + index, *prefix))
+            })
+            .min_by_key(|(index, _)| *index)?;
+
+        let at_boundary = text[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| c.is_whitespace() || matches!(c, '(' | '*' | '_' | '~'));
+        let end = url_end(text, start);
+
+        if at_boundary && end > start + prefix.len() {
+            return Some((start, end));
+        }
+        search_from = start + prefix.len();
+    }
+}
+
+/// End of the URL starting at `start`: up to the next whitespace or `<`, without
+/// trailing punctuation and without a closing parenthesis that has no opening one.
+fn url_end(text: &str, start: usize) -> usize {
+    let candidate = &text[start..];
+    let mut url = candidate
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .map_or(candidate, |end| &candidate[..end]);
+
+    loop {
+        let trimmed =
+            url.trim_end_matches(['.', ',', ':', ';', '!', '?', '"', '\'', '*', '_', '~']);
+        let trimmed = if trimmed.ends_with(')')
+            && trimmed.matches(')').count() > trimmed.matches('(').count()
+        {
+            &trimmed[..trimmed.len() - 1]
+        } else {
+            trimmed
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+
+    start + url.len()
+}
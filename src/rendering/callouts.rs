@@ -0,0 +1,165 @@
+//! Callout blocks written as GitHub alerts (`> [!WARNING]`), rendered as asides.
+
+use pulldown_cmark::{BlockQuoteKind, CowStr, Event, Tag, TagEnd};
+
+/// Marker for danger callouts. GitHub calls these `[!CAUTION]`, which works too.
+const DANGER_MARKER: &str = "[!DANGER]";
+
+#[derive(Clone, Copy)]
+enum Callout {
+    Note,
+    Tip,
+    Important,
+    Warning,
+    Danger,
+}
+
+impl Callout {
+    fn from_kind(kind: BlockQuoteKind) -> Self {
+        match kind {
+            BlockQuoteKind::Note => Self::Note,
+            BlockQuoteKind::Tip => Self::Tip,
+            BlockQuoteKind::Important => Self::Important,
+            BlockQuoteKind::Warning => Self::Warning,
+            BlockQuoteKind::Caution => Self::Danger,
+        }
+    }
+
+    fn class(self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Tip => "tip",
+            Self::Important => "important",
+            Self::Warning => "warning",
+            Self::Danger => "danger",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Note => "Note",
+            Self::Tip => "Tip",
+            Self::Important => "Important",
+            Self::Warning => "Warning",
+            Self::Danger => "Danger",
+        }
+    }
+}
+
+/// Turns alert blockquotes into `<aside class="callout callout-*">` with a title.
+/// Ordinary blockquotes are left alone.
+pub(super) fn render_callouts(mut events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    mark_danger_quotes(&mut events);
+
+    let mut output = Vec::with_capacity(events.len());
+    // One entry per open blockquote: whether it was turned into a callout
+    let mut open_quotes: Vec<bool> = Vec::new();
+
+    for event in events {
+        match event {
+            Event::Start(Tag::BlockQuote(Some(kind))) => {
+                let callout = Callout::from_kind(kind);
+                open_quotes.push(true);
+                output.push(Event::Html(CowStr::from(format!(
+                    r#"<aside class="callout callout-{}" role="note"><p class="callout-title">{}</p>"#,
+                    callout.class(),
+                    callout.title()
+                ))));
+            }
+            Event::Start(Tag::BlockQuote(None)) => {
+                open_quotes.push(false);
+                output.push(event);
+            }
+            Event::End(TagEnd::BlockQuote(_)) => {
+                if open_quotes.pop().unwrap_or(false) {
+                    output.push(Event::Html(CowStr::Borrowed("</aside>")));
+                } else {
+                    output.push(Event::End(TagEnd::BlockQuote(None)));
+                }
+            }
+            other => output.push(other),
+        }
+    }
+
+    output
+}
+
+/// The parser leaves `> [!DANGER]` as plain text. Marks such blockquotes as callouts
+/// and removes the marker from their first paragraph.
+fn mark_danger_quotes(events: &mut Vec<Event<'_>>) {
+    let mut index = 0;
+    while index + 2 < events.len() {
+        let rest = match (&events[index], &events[index + 1], &events[index + 2]) {
+            (
+                Event::Start(Tag::BlockQuote(None)),
+                Event::Start(Tag::Paragraph),
+                Event::Text(text),
+            ) => text
+                .trim_start()
+                .strip_prefix(DANGER_MARKER)
+                .map(|rest| rest.trim_start().to_string()),
+            _ => None,
+        };
+
+        if let Some(rest) = rest {
+            events[index] = Event::Start(Tag::BlockQuote(Some(BlockQuoteKind::Caution)));
+            if rest.is_empty() {
+                // Drop the marker and the line break after it
+                let end = if matches!(
+                    events.get(index + 3),
+                    Some(Event::SoftBreak | Event::HardBreak)
+                ) {
+                    index + 4
+                } else {
+                    index + 3
+                };
+                events.drain(index + 2..end);
+                // A marker on its own paragraph leaves an empty one behind
+                if matches!(events.get(index + 2), Some(Event::End(TagEnd::Paragraph))) {
+                    events.drain(index + 1..index + 3);
+                }
+            } else {
+                events[index + 2] = Event::Text(CowStr::from(rest));
+            }
+        }
+        index += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rendering::render_markdown;
+
+    #[test]
+    fn alert_blockquotes_survive_sanitizing() {
+        let html = render_markdown("> [!TIP]\n> Use `cargo fix`.\n").html;
+
+        assert!(
+            html.contains(
+                r#"<aside class="callout callout-tip" role="note"><p class="callout-title">Tip</p>"#
+            ),
+            "{html}"
+        );
+        assert!(html.contains("</aside>"), "{html}");
+    }
+
+    #[test]
+    fn danger_marker_becomes_a_danger_callout() {
+        let html = render_markdown("> [!DANGER]\n> Deletes everything.\n").html;
+
+        assert!(
+            html.contains(r#"<aside class="callout callout-danger""#),
+            "{html}"
+        );
+        assert!(!html.contains("[!DANGER]"), "{html}");
+        assert!(html.contains("Deletes everything."), "{html}");
+    }
+
+    #[test]
+    fn leaves_plain_blockquotes_alone() {
+        let html = render_markdown("> Just a quote.\n").html;
+
+        assert!(html.contains("<blockquote>"), "{html}");
+        assert!(!html.contains("callout"), "{html}");
+    }
+}
@@ -0,0 +1,68 @@
+use pulldown_cmark::{CodeBlockKind, CowStr};
+
+use super::highlight::highlight;
+
+/// Language of a code block, taken from the first word of a fenced block's info
+/// string (`rust,ignore` and `rust {.class}` both give `rust`).
+pub(super) fn language_from_kind(kind: &CodeBlockKind<'_>) -> Option<String> {
+    let CodeBlockKind::Fenced(info) = kind else {
+        return None;
+    };
+    language_from_info(info)
+}
+
+fn language_from_info(info: &CowStr<'_>) -> Option<String> {
+    let language = info
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    // The language ends up in class names, so only accept identifier-like values
+    let is_valid = !language.is_empty()
+        && language
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '#'));
+
+    is_valid.then_some(language)
+}
+
+/// Human readable name shown in the code block header.
+#[must_use]
+pub fn language_display_name(language: &str) -> &str {
+    match language {
+        "rust" | "rs" => "Rust",
+        "python" | "py" => "Python",
+        "javascript" | "js" => "JavaScript",
+        "typescript" | "ts" => "TypeScript",
+        "json" => "JSON",
+        "yaml" | "yml" => "YAML",
+        "toml" => "TOML",
+        "bash" | "shell" | "sh" | "zsh" => "Shell",
+        "html" => "HTML",
+        "css" => "CSS",
+        "sql" => "SQL",
+        "go" => "Go",
+        "c" => "C",
+        "cpp" | "c++" => "C++",
+        "csharp" | "c#" | "cs" => "C#",
+        "java" => "Java",
+        "dockerfile" | "docker" => "Dockerfile",
+        _ => "Code",
+    }
+}
+
+/// Code block with a header showing the language and a copy button. The language is
+/// kept in `data-language` and in the `language-*` class, and the code is highlighted
+/// when the language is known. The button carries no inline handler; the page script
+/// listens for clicks on `.copy-button`.
+pub(super) fn render_code_block(language: Option<&str>, source: &str) -> String {
+    let language = language.unwrap_or("text");
+    let code = highlight(language, source)
+        .unwrap_or_else(|| html_escape::encode_text(source).into_owned());
+
+    format!(
+        r#"<div class="code-block-wrapper my-8 rounded-lg overflow-hidden shadow-lg border border-gray-200"><div class="code-block-header bg-gray-800 text-gray-300 px-4 py-3 text-sm font-medium flex items-center justify-between"><span class="language-label font-semibold">{label}</span><button class="copy-button text-gray-400 hover:text-white transition-colors p-1 rounded hover:bg-gray-700" title="Copy to clipboard"><svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24"><path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z"></path></svg></button></div><pre class="language-{language} !mt-0 !rounded-t-none !border-0" data-language="{language}"><code class="language-{language}">{code}</code></pre></div>"#,
+        label = language_display_name(language),
+    )
+}
@@ -0,0 +1,164 @@
+//! GitHub style footnotes: numbered references and a list of notes at the end of the
+//! content, each linking back to where it was referenced.
+//!
+//! Ids use the footnote number rather than the label, since different labels can
+//! slugify to the same text (`[^a b]` and `[^a-b]`).
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+
+struct Footnote<'a> {
+    number: usize,
+    content: Vec<Event<'a>>,
+    references: usize,
+}
+
+/// Replaces footnote references with numbered links and moves the definitions of
+/// referenced footnotes into a list at the end. Unreferenced definitions are dropped.
+pub(super) fn collect_footnotes(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    let mut output = Vec::with_capacity(events.len());
+    let mut definitions: HashMap<String, Vec<Event<'_>>> = HashMap::new();
+    let mut footnotes: Vec<Footnote<'_>> = Vec::new();
+    let mut numbers: HashMap<String, usize> = HashMap::new();
+    let mut events = events.into_iter();
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::FootnoteDefinition(label)) => {
+                let content = events
+                    .by_ref()
+                    .take_while(|event| !matches!(event, Event::End(TagEnd::FootnoteDefinition)))
+                    .collect();
+                definitions.insert(label.to_string(), content);
+            }
+            Event::FootnoteReference(label) => {
+                let number = *numbers.entry(label.to_string()).or_insert_with(|| {
+                    footnotes.push(Footnote {
+                        number: footnotes.len() + 1,
+                        content: Vec::new(),
+                        references: 0,
+                    });
+                    footnotes.len()
+                });
+                let footnote = &mut footnotes[number - 1];
+                footnote.references += 1;
+
+                output.push(Event::InlineHtml(CowStr::from(format!(
+                    r##"<sup class="footnote-ref" id="{}"><a href="#fn-{number}">{number}</a></sup>"##,
+                    reference_id(number, footnote.references),
+                ))));
+            }
+            other => output.push(other),
+        }
+    }
+
+    if footnotes.is_empty() {
+        return output;
+    }
+
+    for (label, number) in &numbers {
+        if let Some(content) = definitions.remove(label) {
+            footnotes[number - 1].content = content;
+        }
+    }
+
+    output.push(Event::Html(CowStr::Borrowed(
+        r#"<section class="footnotes"><ol>"#,
+    )));
+    for footnote in footnotes {
+        output.push(Event::Html(CowStr::from(format!(
+            r#"<li id="fn-{}">"#,
+            footnote.number
+        ))));
+        let back_references = Event::InlineHtml(CowStr::from(back_references(&footnote)));
+        let mut content = footnote.content;
+        // Put the links at the end of the last paragraph rather than on a line of their own
+        if matches!(content.last(), Some(Event::End(TagEnd::Paragraph))) {
+            content.insert(content.len() - 1, back_references);
+        } else {
+            content.push(back_references);
+        }
+        output.extend(content);
+        output.push(Event::Html(CowStr::Borrowed("</li>")));
+    }
+    output.push(Event::Html(CowStr::Borrowed("</ol></section>")));
+
+    output
+}
+
+fn reference_id(number: usize, occurrence: usize) -> String {
+    if occurrence == 1 {
+        format!("fnref-{number}")
+    } else {
+        format!("fnref-{number}-{occurrence}")
+    }
+}
+
+fn back_references(footnote: &Footnote<'_>) -> String {
+    (1..=footnote.references).fold(String::new(), |mut links, occurrence| {
+        let _ = write!(
+            links,
+            r##" <a href="#{}" class="footnote-backref" aria-label="Back to reference">↩</a>"##,
+            reference_id(footnote.number, occurrence)
+        );
+        links
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rendering::render_markdown;
+
+    #[test]
+    fn numbers_footnotes_in_reference_order() {
+        let html = render_markdown("One[^b] two[^a].\n\n[^a]: First.\n[^b]: Second.\n").html;
+
+        assert!(
+            html.contains(r##"<sup class="footnote-ref" id="fnref-1"><a href="#fn-1""##),
+            "{html}"
+        );
+        assert!(html.contains(r#"<li id="fn-1">"#), "{html}");
+        assert!(html.find("Second.") < html.find("First."), "{html}");
+    }
+
+    #[test]
+    fn labels_with_the_same_slug_get_distinct_ids() {
+        let html = render_markdown("A[^a b] and B[^a-b].\n\n[^a b]: Space.\n[^a-b]: Dash.\n").html;
+
+        let positions = [r#"<li id="fn-1">"#, "Space.", r#"<li id="fn-2">"#, "Dash."]
+            .map(|needle| html.find(needle));
+        assert!(positions.iter().all(Option::is_some), "{html}");
+        assert!(positions.is_sorted(), "{html}");
+    }
+
+    #[test]
+    fn back_references_survive_sanitizing() {
+        let html = render_markdown("Once[^note], twice[^note].\n\n[^note]: The note.\n").html;
+
+        assert!(
+            html.contains(r#"<sup class="footnote-ref" id="fnref-1-2">"#),
+            "{html}"
+        );
+        assert!(html.contains(r#"<section class="footnotes">"#), "{html}");
+        assert!(
+            html.contains(
+                r##"<a href="#fnref-1" class="footnote-backref" aria-label="Back to reference""##
+            ),
+            "{html}"
+        );
+        assert!(
+            html.contains(r##"<a href="#fnref-1-2" class="footnote-backref""##),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn drops_unreferenced_definitions() {
+        let html = render_markdown("No notes.\n\n[^unused]: Never shown.\n").html;
+
+        assert!(!html.contains("Never shown"), "{html}");
+        assert!(!html.contains("footnotes"), "{html}");
+    }
+}
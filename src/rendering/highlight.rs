@@ -0,0 +1,58 @@
+//! Class-based syntax highlighting with syntect. Colors come from
+//! `public/assets/syntax.css`, so the HTML carries no inline styles.
+//!
+//! Highlighting runs on the server only; the client renders code blocks as plain
+//! escaped text, which is what the highlighter falls back to as well.
+
+/// Prefix of every class the highlighter emits, e.g. `hl-keyword`.
+#[cfg(feature = "ssr")]
+const CLASS_PREFIX: &str = "hl-";
+
+/// Highlights `source` as `language`. Returns `None` when the language is unknown,
+/// so the caller can fall back to plain escaped code.
+#[cfg(feature = "ssr")]
+pub(super) fn highlight(language: &str, source: &str) -> Option<String> {
+    use std::sync::LazyLock;
+
+    use syntect::{
+        html::{ClassStyle, ClassedHTMLGenerator},
+        parsing::SyntaxSet,
+        util::LinesWithEndings,
+    };
+
+    static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+    let syntax = SYNTAX_SET.find_syntax_by_token(syntax_token(language))?;
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(
+        syntax,
+        &SYNTAX_SET,
+        ClassStyle::SpacedPrefixed {
+            prefix: CLASS_PREFIX,
+        },
+    );
+    for line in LinesWithEndings::from(source) {
+        generator
+            .parse_html_for_line_which_includes_newline(line)
+            .ok()?;
+    }
+
+    Some(generator.finalize())
+}
+
+#[cfg(not(feature = "ssr"))]
+pub(super) fn highlight(_language: &str, _source: &str) -> Option<String> {
+    None
+}
+
+/// Maps fence names that the bundled syntaxes don't know to the closest one they do.
+#[cfg(feature = "ssr")]
+fn syntax_token(language: &str) -> &str {
+    match language {
+        "shell" | "zsh" | "console" => "bash",
+        "typescript" | "ts" | "tsx" | "jsx" => "js",
+        "c#" | "csharp" => "cs",
+        "c++" => "cpp",
+        "text" | "plaintext" => "txt",
+        other => other,
+    }
+}
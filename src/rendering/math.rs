@@ -0,0 +1,108 @@
+//! TeX math (`$inline$` and `$$display$$`) converted to `MathML`, which browsers render
+//! natively without any client-side script.
+
+use latex2mathml::{latex_to_mathml, DisplayStyle};
+use pulldown_cmark::{CowStr, Event};
+
+/// Text the converter writes into its output, instead of failing, for input it cannot
+/// parse, such as a `\frac` missing an argument.
+const PARSE_ERROR_MARKER: &str = "[PARSE ERROR:";
+
+/// Replaces math events with `MathML`. Formulas that fail to convert are shown as their
+/// TeX source with a visible error marker instead of breaking the whole post.
+pub(super) fn render_math(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    events
+        .into_iter()
+        .map(|event| match event {
+            Event::InlineMath(source) => {
+                Event::InlineHtml(CowStr::from(to_mathml(&source, DisplayStyle::Inline)))
+            }
+            Event::DisplayMath(source) => {
+                Event::InlineHtml(CowStr::from(to_mathml(&source, DisplayStyle::Block)))
+            }
+            other => other,
+        })
+        .collect()
+}
+
+fn to_mathml(source: &str, style: DisplayStyle) -> String {
+    let (class, delimiter) = match style {
+        DisplayStyle::Block => ("math-display", "$$"),
+        DisplayStyle::Inline => ("math-inline", "$"),
+    };
+
+    let error = match latex_to_mathml(source, style) {
+        Ok(mathml) if !mathml.contains(PARSE_ERROR_MARKER) => {
+            return format!(r#"<span class="math {class}">{mathml}</span>"#);
+        }
+        Ok(_) => "Unsupported or incomplete TeX".to_string(),
+        Err(error) => error.to_string(),
+    };
+
+    format!(
+        r#"<span class="math math-error" title="{}"><code>{delimiter}{}{delimiter}</code><span class="math-error-marker">Invalid formula</span></span>"#,
+        html_escape::encode_double_quoted_attribute(&error),
+        html_escape::encode_text(source),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::rendering::render_markdown;
+
+    #[test]
+    fn renders_inline_math_as_mathml() {
+        let html = render_markdown("Euler: $e^{i\\pi} + 1 = 0$.").html;
+
+        assert!(
+            html.contains(r#"<span class="math math-inline"><math"#),
+            "{html}"
+        );
+        assert!(html.contains("<msup>"), "{html}");
+        assert!(html.contains("<mi>π</mi>"), "{html}");
+    }
+
+    #[test]
+    fn renders_display_math_as_block_mathml() {
+        let html = render_markdown("$$\\frac{a}{b}$$").html;
+
+        assert!(
+            html.contains(r#"<span class="math math-display"><math"#),
+            "{html}"
+        );
+        assert!(html.contains(r#"display="block""#), "{html}");
+        assert!(html.contains("<mfrac>"), "{html}");
+    }
+
+    #[test]
+    fn shows_invalid_tex_as_source_with_an_error_marker() {
+        let html = render_markdown("Broken: $\\frac{1}$ but the post still renders.").html;
+
+        assert!(
+            html.contains(r#"<span class="math math-error" title="#),
+            "{html}"
+        );
+        assert!(html.contains(r"<code>$\frac{1}$</code>"), "{html}");
+        assert!(html.contains("Invalid formula"), "{html}");
+        assert!(html.contains("but the post still renders."), "{html}");
+        assert!(!html.contains("PARSE ERROR"), "{html}");
+    }
+
+    #[test]
+    fn shows_conversion_errors_in_the_title() {
+        let html = render_markdown("$$\\begin{nope} x \\end{nope}$$").html;
+
+        assert!(html.contains(r#"title="An unknown environment"#), "{html}");
+        assert!(
+            html.contains(r"<code>$$\begin{nope} x \end{nope}$$</code>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn leaves_text_without_math_alone() {
+        let html = render_markdown("It costs 5 dollars.").html;
+
+        assert!(!html.contains("math"), "{html}");
+    }
+}
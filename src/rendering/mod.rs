@@ -0,0 +1,199 @@
+//! Markdown to HTML rendering shared by the post page, the feeds and the editor preview.
+//!
+//! Everything that displays post content goes through [`render_markdown`], so the
+//! same source always produces the same HTML. Posts written as plain HTML keep
+//! working: Markdown passes raw HTML through unchanged.
+
+mod autolink;
+mod callouts;
+mod code;
+mod footnotes;
+mod highlight;
+mod math;
+mod toc;
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd, TextMergeStream};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::html::sanitize_html;
+
+pub use code::language_display_name;
+pub use toc::TocEntry;
+
+/// Sanitized HTML produced from a post's Markdown source.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedContent {
+    pub html: String,
+    /// Headings of the content, nested by level.
+    pub toc: Vec<TocEntry>,
+}
+
+/// Renders Markdown with the GitHub flavored extensions we support (tables, task
+/// lists, strikethrough, autolinked URLs, footnotes and `> [!NOTE]` style callouts),
+/// TeX math as `MathML`, and sanitizes the result.
+#[must_use]
+pub fn render_markdown(source: &str) -> RenderedContent {
+    let parser = TextMergeStream::new(Parser::new_ext(source, markdown_options()));
+    let events = math::render_math(callouts::render_callouts(transform_events(parser)));
+    let (events, toc) = toc::anchor_headings(footnotes::collect_footnotes(events));
+
+    let mut html_output = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut html_output, events.into_iter());
+
+    RenderedContent {
+        html: sanitize_html(&html_output),
+        toc,
+    }
+}
+
+fn markdown_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_HEADING_ATTRIBUTES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_GFM
+        | Options::ENABLE_MATH
+}
+
+/// Replaces fenced and indented code blocks with our code block markup and turns
+/// bare URLs in text into links.
+fn transform_events<'a>(events: impl Iterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+    let mut output = Vec::new();
+    let mut code_block: Option<(Option<String>, String)> = None;
+    let mut link_depth = 0usize;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                code_block = Some((code::language_from_kind(&kind), String::new()));
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some((language, source)) = code_block.take() {
+                    output.push(Event::Html(CowStr::from(code::render_code_block(
+                        language.as_deref(),
+                        &source,
+                    ))));
+                }
+            }
+            Event::Text(text) if code_block.is_some() => {
+                if let Some((_, source)) = code_block.as_mut() {
+                    source.push_str(&text);
+                }
+            }
+            Event::Start(Tag::Link { .. } | Tag::Image { .. }) => {
+                link_depth += 1;
+                output.push(event);
+            }
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                link_depth = link_depth.saturating_sub(1);
+                output.push(event);
+            }
+            // Anchors written as raw HTML must not get links nested inside them either
+            Event::InlineHtml(ref tag) if is_anchor_open(tag) => {
+                link_depth += 1;
+                output.push(event);
+            }
+            Event::InlineHtml(ref tag) if tag.trim().eq_ignore_ascii_case("</a>") => {
+                link_depth = link_depth.saturating_sub(1);
+                output.push(event);
+            }
+            Event::Text(text) if link_depth == 0 => {
+                autolink::push_autolinked(&mut output, text);
+            }
+            other => output.push(other),
+        }
+    }
+
+    output
+}
+
+fn is_anchor_open(tag: &str) -> bool {
+    let tag = tag.trim_start().to_ascii_lowercase();
+    tag.starts_with("<a ") || tag.starts_with("<a>") || tag.starts_with("<a\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_tables_with_column_alignment() {
+        let html = render_markdown("| Name | Size |\n|:-----|-----:|\n| a | 1 |\n").html;
+
+        assert!(html.contains("<table>"), "{html}");
+        assert!(
+            html.contains(r#"<th style="text-align:left">Name</th>"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<td style="text-align:right">1</td>"#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn renders_task_lists() {
+        let html = render_markdown("- [x] done\n- [ ] todo\n").html;
+
+        assert!(
+            html.contains(r#"<input disabled="" type="checkbox" checked="">"#),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"<input disabled="" type="checkbox">"#),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn renders_strikethrough() {
+        let html = render_markdown("~~gone~~ kept").html;
+
+        assert!(html.contains("<del>gone</del> kept"), "{html}");
+    }
+
+    #[test]
+    fn links_bare_urls_but_not_urls_inside_links() {
+        let html = render_markdown("See https://example.com/docs.").html;
+        assert!(
+            html.contains(r#"href="https://example.com/docs""#),
+            "{html}"
+        );
+        assert!(html.contains(">https://example.com/docs</a>."), "{html}");
+
+        let html = render_markdown("[https://example.com](https://example.com)").html;
+        assert_eq!(html.matches("<a ").count(), 1, "{html}");
+    }
+
+    #[test]
+    fn marks_code_blocks_with_their_language() {
+        let html = render_markdown("```rust,ignore\nfn main() {}\n```\n").html;
+
+        assert!(html.contains(r#"data-language="rust""#), "{html}");
+        assert!(html.contains(r#"<code class="language-rust">"#), "{html}");
+        assert!(html.contains(r#"<span class="language-label font-semibold">Rust</span>"#));
+    }
+
+    #[test]
+    fn falls_back_to_plain_text_for_unknown_or_missing_languages() {
+        let html = render_markdown("    <b>indented</b>\n").html;
+
+        assert!(html.contains(r#"data-language="text""#), "{html}");
+        assert!(html.contains("&lt;b&gt;indented&lt;/b&gt;"), "{html}");
+    }
+
+    #[test]
+    fn strips_scripts_and_event_handlers_from_raw_html() {
+        let html = render_markdown(
+            "<script>alert(1)</script>\n\n<img src=\"x.png\" onerror=\"alert(2)\">\n\n<a href=\"javascript:alert(3)\" onclick=\"alert(4)\">x</a>\n",
+        )
+        .html;
+
+        assert!(!html.contains("<script"), "{html}");
+        assert!(!html.contains("onerror"), "{html}");
+        assert!(!html.contains("onclick"), "{html}");
+        assert!(!html.contains("javascript:"), "{html}");
+        assert!(html.contains(r#"<img src="x.png">"#), "{html}");
+    }
+}
@@ -0,0 +1,200 @@
+//! Heading ids, permalink anchors and the table of contents built from them.
+
+use std::collections::HashSet;
+
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::slug::{slugify, unique_slug};
+
+/// Deepest heading level listed in the table of contents.
+const TOC_MAX_LEVEL: u8 = 3;
+
+/// A heading in the table of contents with the headings nested under it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub id: String,
+    pub children: Vec<TocEntry>,
+}
+
+struct FlatHeading {
+    level: u8,
+    text: String,
+    id: String,
+}
+
+/// Gives every heading a unique `id` (an explicit `{#id}` wins over the slugified
+/// text) followed by a permalink anchor, and returns the nested table of contents.
+pub(super) fn anchor_headings(events: Vec<Event<'_>>) -> (Vec<Event<'_>>, Vec<TocEntry>) {
+    let mut output = Vec::with_capacity(events.len());
+    let mut headings = Vec::new();
+    let mut used_ids = HashSet::new();
+    let mut events = events.into_iter();
+
+    while let Some(event) = events.next() {
+        let Event::Start(Tag::Heading {
+            level,
+            id,
+            classes,
+            attrs,
+        }) = event
+        else {
+            output.push(event);
+            continue;
+        };
+
+        let inner: Vec<Event<'_>> = events
+            .by_ref()
+            .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+            .collect();
+        let text = heading_text(&inner);
+
+        let base = match id {
+            Some(id) => id.to_string(),
+            None if text.chars().any(char::is_alphanumeric) => slugify(&text),
+            None => "section".to_string(),
+        };
+        let id = unique_slug(&base, &[], |candidate| used_ids.contains(candidate));
+        used_ids.insert(id.clone());
+
+        let level_number = level as u8;
+        if level_number <= TOC_MAX_LEVEL {
+            headings.push(FlatHeading {
+                level: level_number,
+                text,
+                id: id.clone(),
+            });
+        }
+
+        output.push(Event::Start(Tag::Heading {
+            level,
+            id: Some(CowStr::from(id.clone())),
+            classes,
+            attrs,
+        }));
+        output.extend(inner);
+        output.push(Event::InlineHtml(CowStr::from(format!(
+            r##"<a class="heading-anchor" href="#{id}" aria-label="Link to this section">#</a>"##
+        ))));
+        output.push(Event::End(TagEnd::Heading(level)));
+    }
+
+    (output, nest(&headings))
+}
+
+/// Plain text of a heading's content, without markup.
+fn heading_text(events: &[Event<'_>]) -> String {
+    let text: String = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+            Event::SoftBreak | Event::HardBreak => Some(" "),
+            _ => None,
+        })
+        .collect();
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Nests each heading under the closest preceding heading of a higher level.
+fn nest(headings: &[FlatHeading]) -> Vec<TocEntry> {
+    let mut entries = Vec::new();
+    let mut index = 0;
+
+    while let Some(heading) = headings.get(index) {
+        let end = headings[index + 1..]
+            .iter()
+            .position(|next| next.level <= heading.level)
+            .map_or(headings.len(), |offset| index + 1 + offset);
+
+        entries.push(TocEntry {
+            level: heading.level,
+            text: heading.text.clone(),
+            id: heading.id.clone(),
+            children: nest(&headings[index + 1..end]),
+        });
+        index = end;
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rendering::render_markdown;
+
+    fn entry(level: u8, text: &str, id: &str, children: Vec<TocEntry>) -> TocEntry {
+        TocEntry {
+            level,
+            text: text.to_string(),
+            id: id.to_string(),
+            children,
+        }
+    }
+
+    #[test]
+    fn gives_headings_slugged_ids_and_anchors() {
+        let rendered = render_markdown("## Getting *Started* with `cargo`\n");
+
+        assert!(
+            rendered
+                .html
+                .contains(r#"<h2 id="getting-started-with-cargo">"#),
+            "{}",
+            rendered.html
+        );
+        let anchor = r##"<a class="heading-anchor" href="#getting-started-with-cargo""##;
+        assert!(rendered.html.contains(anchor), "{}", rendered.html);
+        assert!(rendered.html.contains(">#</a></h2>"), "{}", rendered.html);
+        assert_eq!(
+            rendered.toc,
+            vec![entry(
+                2,
+                "Getting Started with cargo",
+                "getting-started-with-cargo",
+                vec![]
+            )]
+        );
+    }
+
+    #[test]
+    fn deduplicates_repeated_headings() {
+        let rendered = render_markdown("## Intro\n\n## Intro\n\n## Setup {#intro}\n\n## ???\n");
+        let ids: Vec<&str> = rendered.toc.iter().map(|entry| entry.id.as_str()).collect();
+
+        assert_eq!(ids, ["intro", "intro-2", "intro-3", "section"]);
+        assert!(rendered.html.contains(r#"<h2 id="intro-2">"#));
+    }
+
+    #[test]
+    fn nests_headings_across_skipped_levels() {
+        let rendered = render_markdown(
+            "# Guide\n\n### Deep\n\n## Usage\n\n#### Detail\n\n### Flags\n\n# Next\n",
+        );
+
+        assert_eq!(
+            rendered.toc,
+            vec![
+                entry(
+                    1,
+                    "Guide",
+                    "guide",
+                    vec![
+                        entry(3, "Deep", "deep", vec![]),
+                        entry(
+                            2,
+                            "Usage",
+                            "usage",
+                            vec![entry(3, "Flags", "flags", vec![])]
+                        ),
+                    ]
+                ),
+                entry(1, "Next", "next", vec![]),
+            ]
+        );
+        // Too deep for the table of contents, but still linkable
+        assert!(rendered.html.contains(r#"<h4 id="detail">"#));
+    }
+}
@@ -0,0 +1,71 @@
+//! Authorization for admin-only server functions.
+//!
+//! Admin calls authenticate with HTTP Basic credentials matching
+//! `DEV_SITE_ADMIN_USERNAME` and `DEV_SITE_ADMIN_PASSWORD`. Without a configured
+//! password every admin call is refused.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use http::{header::AUTHORIZATION, HeaderMap};
+use leptos::{
+    logging,
+    prelude::{use_context, ServerFnError},
+};
+use sha2::{Digest, Sha256};
+
+use crate::services::config::{try_get_config, AdminConfig};
+
+/// Fails unless the current request carries the admin credentials.
+///
+/// # Errors
+///
+/// Returns an error, and sets `401 Unauthorized` on the response, if the
+/// credentials are missing or wrong.
+pub async fn require_admin() -> Result<(), ServerFnError> {
+    let headers: HeaderMap = leptos_axum::extract().await?;
+    let authorized = try_get_config().is_ok_and(|config| is_authorized(&headers, &config.admin));
+    if authorized {
+        return Ok(());
+    }
+
+    logging::warn!("Rejected unauthorized admin request");
+    if let Some(response) = use_context::<leptos_axum::ResponseOptions>() {
+        response.set_status(http::StatusCode::UNAUTHORIZED);
+    }
+    Err(ServerFnError::new("Admin credentials required"))
+}
+
+/// Whether `headers` carry Basic credentials matching `admin`.
+#[must_use]
+pub fn is_authorized(headers: &HeaderMap, admin: &AdminConfig) -> bool {
+    let Some(password) = admin.password.as_ref() else {
+        return false;
+    };
+    let Some((username, given_password)) = basic_credentials(headers) else {
+        return false;
+    };
+
+    let username_matches = admin
+        .username
+        .as_deref()
+        .is_none_or(|expected| same_secret(expected, &username));
+    // Both checks always run, so timing doesn't reveal which one failed
+    let password_matches = same_secret(password.expose_secret(), &given_password);
+    username_matches && password_matches
+}
+
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, encoded) = value.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("basic") {
+        return None;
+    }
+    let decoded = String::from_utf8(STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (username, password) = decoded.split_once(':')?;
+    Some((username.to_string(), password.to_string()))
+}
+
+/// Compares digests rather than the values, so the comparison time says nothing
+/// about how much of the secret was guessed right.
+fn same_secret(expected: &str, given: &str) -> bool {
+    Sha256::digest(expected.as_bytes()) == Sha256::digest(given.as_bytes())
+}
@@ -1,11 +1,19 @@
 use color_eyre::{eyre::WrapErr, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::env::VarError;
+use std::path::PathBuf;
+
+use crate::services::secret::SecretString;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub cosmos: CosmosConfig,
     pub server: ServerConfig,
+    pub admin: AdminConfig,
+    pub smtp: Option<SmtpConfig>,
+    pub robots: RobotsConfig,
+    pub site: SiteConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -13,7 +21,7 @@ pub struct CosmosConfig {
     pub uri: String,
     pub database_name: String,
     pub containers: HashMap<String, ContainerConfig>,
-    pub primary_key: String, // For development only
+    pub primary_key: SecretString, // For development only
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +35,56 @@ pub struct ContainerConfig {
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// Directory where generated social card images are cached.
+    pub og_image_cache_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminConfig {
+    pub username: Option<String>,
+    pub password: Option<SecretString>,
+    /// Keys used to sign sessions and links. The first key signs, all keys verify,
+    /// which allows rotating keys without invalidating everything at once.
+    pub session_signing_keys: Vec<SecretString>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: String,
+    pub password: SecretString,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RobotsConfig {
+    /// When false, `/robots.txt` disallows everything (e.g. for staging slots).
+    pub allow_indexing: bool,
+    /// Path prefixes crawlers should stay out of.
+    pub disallow: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SiteConfig {
+    /// Public base URL without a trailing slash, used for absolute links in feeds and metadata.
+    pub base_url: String,
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        Self {
+            base_url: "http://localhost:3000".to_string(),
+        }
+    }
+}
+
+impl Default for RobotsConfig {
+    fn default() -> Self {
+        Self {
+            allow_indexing: true,
+            disallow: vec!["/api/".to_string(), "/admin/".to_string()],
+        }
+    }
 }
 
 impl AppConfig {
@@ -40,13 +98,24 @@ impl AppConfig {
     /// - `COSMOS_DB_DATABASE` - The name of the Cosmos database
     /// - `COSMOS_DB_KEY` - The primary key for Cosmos DB access
     pub fn from_env() -> Result<Self> {
-        let cosmos_db_uri = std::env::var("COSMOS_DB_URI")
-            .wrap_err("COSMOS_DB_URI environment variable not set")?;
+        Self::from_vars(|key| std::env::var(key))
+    }
+
+    /// Creates an `AppConfig` from an arbitrary variable source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a required variable is missing, see [`AppConfig::from_env`].
+    /// Error messages only ever name the variable, never its value.
+    #[allow(clippy::too_many_lines)]
+    pub fn from_vars(var: impl Fn(&str) -> Result<String, VarError>) -> Result<Self> {
+        let cosmos_db_uri =
+            var("COSMOS_DB_URI").wrap_err("COSMOS_DB_URI environment variable not set")?;
 
-        let cosmos_database_name = std::env::var("COSMOS_DB_DATABASE")
+        let cosmos_database_name = var("COSMOS_DB_DATABASE")
             .wrap_err("COSMOS_DB_DATABASE environment variable not set")?;
 
-        let cosmos_primary_key = std::env::var("COSMOS_DB_KEY").wrap_err(
+        let cosmos_primary_key = var("COSMOS_DB_KEY").map(SecretString::from).wrap_err(
             "COSMOS_DB_KEY environment variable not set. This is required for development.",
         )?;
 
@@ -57,7 +126,7 @@ impl AppConfig {
         containers.insert(
             "blogs".to_string(),
             ContainerConfig {
-                name: std::env::var("COSMOS_BLOGS_CONTAINER_NAME")
+                name: var("COSMOS_BLOGS_CONTAINER_NAME")
                     .unwrap_or_else(|_| "posts".to_string()),
                 partition_key: "author".to_string(),
                 throughput: Some(400),
@@ -68,13 +137,35 @@ impl AppConfig {
         containers.insert(
             "books".to_string(),
             ContainerConfig {
-                name: std::env::var("COSMOS_BOOKS_CONTAINER_NAME")
+                name: var("COSMOS_BOOKS_CONTAINER_NAME")
                     .unwrap_or_else(|_| "books".to_string()),
                 partition_key: "category".to_string(),
                 throughput: Some(400),
             },
         );
 
+        // Post series container, in the same partition as the posts
+        containers.insert(
+            "series".to_string(),
+            ContainerConfig {
+                name: var("COSMOS_SERIES_CONTAINER_NAME")
+                    .unwrap_or_else(|_| "series".to_string()),
+                partition_key: "author".to_string(),
+                throughput: Some(400),
+            },
+        );
+
+        // Draft preview links, each with a snapshot of the previewed post
+        containers.insert(
+            "previews".to_string(),
+            ContainerConfig {
+                name: var("COSMOS_PREVIEWS_CONTAINER_NAME")
+                    .unwrap_or_else(|_| "previews".to_string()),
+                partition_key: "author".to_string(),
+                throughput: Some(400),
+            },
+        );
+
         // Add more containers as needed
         // containers.insert("users".to_string(), ContainerConfig {
         //     name: std::env::var("COSMOS_USERS_CONTAINER_NAME")
@@ -91,16 +182,78 @@ impl AppConfig {
         };
 
         let server_config = ServerConfig {
-            host: std::env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
-            port: std::env::var("SERVER_PORT")
+            host: var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
+            port: var("SERVER_PORT")
                 .unwrap_or_else(|_| "3000".to_string())
                 .parse()
                 .unwrap_or(3000),
+            og_image_cache_dir: var("OG_IMAGE_CACHE_DIR").map_or_else(
+                |_| std::env::temp_dir().join("mike-dev-blog").join("og"),
+                PathBuf::from,
+            ),
+        };
+
+        let admin_config = AdminConfig {
+            username: var("DEV_SITE_ADMIN_USERNAME").ok(),
+            password: var("DEV_SITE_ADMIN_PASSWORD").ok().map(SecretString::from),
+            session_signing_keys: var("SESSION_SIGNING_KEYS")
+                .map(|keys| {
+                    keys.split(',')
+                        .map(str::trim)
+                        .filter(|key| !key.is_empty())
+                        .map(SecretString::new)
+                        .collect()
+                })
+                .unwrap_or_default(),
+        };
+
+        // SMTP is optional; once a host is configured the credentials become required
+        let smtp_config = match var("SMTP_HOST") {
+            Ok(host) => Some(SmtpConfig {
+                host,
+                port: var("SMTP_PORT")
+                    .unwrap_or_else(|_| "587".to_string())
+                    .parse()
+                    .unwrap_or(587),
+                username: var("SMTP_USERNAME")
+                    .wrap_err("SMTP_USERNAME environment variable not set")?,
+                password: var("SMTP_PASSWORD")
+                    .map(SecretString::from)
+                    .wrap_err("SMTP_PASSWORD environment variable not set")?,
+            }),
+            Err(_) => None,
+        };
+
+        let robots_config = RobotsConfig {
+            allow_indexing: var("ROBOTS_ALLOW_INDEXING")
+                .map_or(true, |value| !matches!(value.trim(), "0" | "false" | "no")),
+            disallow: var("ROBOTS_DISALLOW").map_or_else(
+                |_| RobotsConfig::default().disallow,
+                |paths| {
+                    paths
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|path| !path.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                },
+            ),
+        };
+
+        let site_config = SiteConfig {
+            base_url: var("SITE_URL").map_or_else(
+                |_| SiteConfig::default().base_url,
+                |url| url.trim().trim_end_matches('/').to_string(),
+            ),
         };
 
         Ok(AppConfig {
             cosmos: cosmos_config,
             server: server_config,
+            admin: admin_config,
+            smtp: smtp_config,
+            robots: robots_config,
+            site: site_config,
         })
     }
 
@@ -110,6 +263,7 @@ impl AppConfig {
     }
 }
 
+// Only the error message is kept. It names missing variables but never contains their values.
 static APP_CONFIG: std::sync::LazyLock<miette::Result<AppConfig>> =
     std::sync::LazyLock::new(|| {
         let config = AppConfig::from_env();
@@ -121,23 +275,31 @@ static APP_CONFIG: std::sync::LazyLock<miette::Result<AppConfig>> =
         }
     });
 
+/// Gets the application configuration without panicking.
+///
+/// # Errors
+///
+/// Returns the error produced while loading the configuration from the environment.
+pub fn try_get_config() -> Result<&'static AppConfig, &'static miette::Report> {
+    APP_CONFIG.as_ref()
+}
+
 /// Gets the application configuration.
 ///
 /// # Panics
 ///
 /// Panics if required environment variables are not set:
 /// - `COSMOS_DB_URI`
+/// - `COSMOS_DB_DATABASE`
 /// - `COSMOS_DB_KEY`
-/// - `DEV_SITE_ADMIN_USERNAME`
-/// - `DEV_SITE_ADMIN_PASSWORD`
-/// - `SESSION_TIMEOUT_HOURS` (if set but not a valid number)
+/// - `SMTP_USERNAME` / `SMTP_PASSWORD` (if `SMTP_HOST` is set)
 ///
 pub fn get_config() -> &'static AppConfig {
     match APP_CONFIG.as_ref() {
         Ok(config) => config,
         Err(e) => {
-            panic!("{}", format!(
-                "Failed to load application configuration. Please ensure all required environment variables are set. Error:\n{e}")
+            panic!(
+                "Failed to load application configuration. Please ensure all required environment variables are set. Error:\n{e}"
             );
         }
     }
@@ -150,6 +312,92 @@ pub fn get_config() -> &'static AppConfig {
 /// This function will return an error if the configuration cannot be loaded,
 /// which can happen when required environment variables are not set.
 pub fn init_config() -> Result<()> {
-    get_config();
+    try_get_config().map_err(|e| color_eyre::eyre::eyre!("{e}"))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COSMOS_KEY: &str = "cosmos-primary-key-do-not-log";
+    const ADMIN_PASSWORD: &str = "admin-password-do-not-log";
+    const SIGNING_KEY: &str = "session-signing-key-do-not-log";
+    const SMTP_PASSWORD: &str = "smtp-password-do-not-log";
+
+    fn vars(entries: &[(&str, &str)]) -> impl Fn(&str) -> Result<String, VarError> {
+        let map: HashMap<String, String> = entries
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect();
+        move |key| map.get(key).cloned().ok_or(VarError::NotPresent)
+    }
+
+    fn assert_no_secrets(output: &str) {
+        for secret in [COSMOS_KEY, ADMIN_PASSWORD, SIGNING_KEY, SMTP_PASSWORD] {
+            assert!(!output.contains(secret), "secret leaked into: {output}");
+        }
+    }
+
+    #[test]
+    fn config_output_never_contains_secrets() {
+        let config = AppConfig::from_vars(vars(&[
+            ("COSMOS_DB_URI", "https://localhost:8081"),
+            ("COSMOS_DB_DATABASE", "blog"),
+            ("COSMOS_DB_KEY", COSMOS_KEY),
+            ("DEV_SITE_ADMIN_USERNAME", "mike"),
+            ("DEV_SITE_ADMIN_PASSWORD", ADMIN_PASSWORD),
+            ("SESSION_SIGNING_KEYS", SIGNING_KEY),
+            ("SMTP_HOST", "smtp.example.com"),
+            ("SMTP_USERNAME", "mailer"),
+            ("SMTP_PASSWORD", SMTP_PASSWORD),
+        ]));
+        let Ok(config) = config else {
+            panic!("config should load from complete variables");
+        };
+
+        assert_eq!(config.cosmos.primary_key.expose_secret(), COSMOS_KEY);
+        assert_no_secrets(&format!("{config:?}"));
+        assert_no_secrets(&format!("{config:#?}"));
+        assert_no_secrets(&serde_json::to_string(&config).unwrap_or_default());
+    }
+
+    #[test]
+    fn config_errors_never_contain_secrets() {
+        // COSMOS_DB_URI is missing while every secret is present
+        let result = AppConfig::from_vars(vars(&[
+            ("COSMOS_DB_DATABASE", "blog"),
+            ("COSMOS_DB_KEY", COSMOS_KEY),
+            ("DEV_SITE_ADMIN_PASSWORD", ADMIN_PASSWORD),
+            ("SESSION_SIGNING_KEYS", SIGNING_KEY),
+            ("SMTP_PASSWORD", SMTP_PASSWORD),
+        ]));
+        let Err(error) = result else {
+            panic!("config without COSMOS_DB_URI should fail");
+        };
+
+        assert_no_secrets(&format!("{error}"));
+        assert_no_secrets(&format!("{error:?}"));
+        assert_no_secrets(&format!("{error:#}"));
+    }
+
+    #[test]
+    fn site_url_is_read_at_runtime_without_trailing_slash() {
+        let required = [
+            ("COSMOS_DB_URI", "https://localhost:8081"),
+            ("COSMOS_DB_DATABASE", "blog"),
+            ("COSMOS_DB_KEY", COSMOS_KEY),
+        ];
+        let default_url = AppConfig::from_vars(vars(&required))
+            .map(|config| config.site.base_url)
+            .unwrap_or_default();
+        assert_eq!(default_url, "http://localhost:3000");
+
+        let mut entries = required.to_vec();
+        entries.push(("SITE_URL", "https://blog.example.com/"));
+        let configured_url = AppConfig::from_vars(vars(&entries))
+            .map(|config| config.site.base_url)
+            .unwrap_or_default();
+        assert_eq!(configured_url, "https://blog.example.com");
+    }
+}
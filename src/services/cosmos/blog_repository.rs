@@ -1,12 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
 use azure_core::error::Error as AzureError;
-use azure_data_cosmos::PartitionKey;
+use azure_data_cosmos::{PartitionKey, Query};
+use chrono::{DateTime, Utc};
 use color_eyre::{eyre::WrapErr, Result};
 use futures::TryStreamExt;
 use leptos::leptos_dom::logging;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+
+use crate::{
+    models::{
+        archive::{ArchiveMonth, ArchivePeriod},
+        blog::{post_path, BlogPost, TagCount, Visibility, RESERVED_POST_SLUGS},
+        blog_index::PostSort,
+    },
+    services::cosmos::CosmosClientManager,
+    utils::{
+        content_analysis::ContentStats,
+        datetime::storage_timestamp,
+        slug::{slugify, unique_slug},
+    },
+};
+
+pub(crate) const PARTITION_KEY: &str = "Mikko Leppänen";
+
+/// Condition matching the posts listed at `@now`, see [`BlogPost::is_listed_at`]: public,
+/// or drafts with a publish time that has passed, and not expired. Every query behind a
+/// public list, feed or sitemap filters with it, so drafts and unlisted posts never leak.
+/// `@now` is bound with [`storage_timestamp`] to match the stored precision.
+const LISTED: &str =
+    "(c.visibility = 'public' OR (c.visibility = 'draft' AND c.publish_at <= @now)) \
+     AND (NOT IS_DEFINED(c.unpublish_at) OR IS_NULL(c.unpublish_at) OR c.unpublish_at > @now)";
+
+/// Projection of a published post with just the fields a sitemap needs.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SitemapEntry {
+    pub id: String,
+    #[serde(default)]
+    pub slug: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SitemapEntry {
+    /// Canonical path of the post, see [`post_path`].
+    #[must_use]
+    pub fn path(&self) -> String {
+        post_path(&self.slug, &self.id)
+    }
+}
+
+/// Narrows the published posts down for the paginated blog index.
+#[derive(Debug, Clone, Default)]
+pub struct PostFilter {
+    /// Matches posts carrying any of these tag spellings.
+    pub tags: Option<Vec<String>>,
+    /// Matches posts published in this calendar year (UTC).
+    pub year: Option<i32>,
+    /// Narrows `year` down to this one-based month. Ignored without a year.
+    pub month: Option<u32>,
+}
+
+impl PostFilter {
+    /// Builds `{select} WHERE {LISTED} ...filters... {suffix}` with the filter parameters bound.
+    fn query(&self, select: &str, suffix: &str) -> Result<Query> {
+        let mut sql = format!("{select} WHERE {LISTED}");
+        if self.tags.is_some() {
+            sql.push_str(
+                " AND EXISTS(SELECT VALUE t FROM t IN c.tags WHERE ARRAY_CONTAINS(@tags, t))",
+            );
+        }
+        let date_range = self.date_range();
+        if date_range.is_some() {
+            sql.push_str(" AND c.created_at >= @from AND c.created_at < @to");
+        }
+        sql.push(' ');
+        sql.push_str(suffix);
+
+        let mut query = listed_query(sql)?;
+        if let Some(tags) = &self.tags {
+            query = query
+                .with_parameter("@tags", tags)
+                .wrap_err("Failed to build tag filter")?;
+        }
+        if let Some((from, to)) = date_range {
+            query = query
+                .with_parameter("@from", from)
+                .wrap_err("Failed to build date filter")?
+                .with_parameter("@to", to)
+                .wrap_err("Failed to build date filter")?;
+        }
+        Ok(query)
+    }
 
-const PARTITION_KEY: &str = "Mikko Leppänen";
+    /// Bounds of the filtered year or month. Timestamps are stored as RFC 3339 strings,
+    /// so `"2024-03" <= created_at < "2024-04"` matches every post from March 2024.
+    fn date_range(&self) -> Option<(String, String)> {
+        let year = self.year?;
+        Some(match self.month {
+            Some(month) if month >= 12 => (format!("{year}-12"), format!("{}-01", year + 1)),
+            Some(month) => (
+                format!("{year}-{month:02}"),
+                format!("{year}-{:02}", month + 1),
+            ),
+            None => (year.to_string(), (year + 1).to_string()),
+        })
+    }
+}
 
-use crate::{models::blog::BlogPost, services::cosmos::CosmosClientManager};
+/// Projection of a published post with the fields used to score related posts.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PostSummary {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub series_id: Option<String>,
+    #[serde(default)]
+    pub content_stats: Option<ContentStats>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Projection with the scheduling timestamps of a post.
+#[derive(Debug, Clone, Deserialize)]
+struct ScheduleRecord {
+    #[serde(default)]
+    publish_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    unpublish_at: Option<DateTime<Utc>>,
+}
+
+/// Number of posts published in a month, as grouped by Cosmos DB.
+#[derive(Debug, Clone, Deserialize)]
+struct MonthCount {
+    /// `YYYY-MM`
+    month: String,
+    count: u64,
+}
+
+/// Projection used to find out which slugs are already in use.
+#[derive(Debug, Clone, Deserialize)]
+struct SlugRecord {
+    #[serde(default)]
+    slug: String,
+    #[serde(default)]
+    previous_slugs: Vec<String>,
+}
 
 pub struct BlogService {
     client: CosmosClientManager,
@@ -33,8 +173,12 @@ impl BlogService {
     /// connecting to the Cosmos DB service.
     pub async fn create_blog(
         &self,
-        blog: BlogPost,
+        mut blog: BlogPost,
     ) -> Result<BlogPost, Box<dyn std::error::Error + Send + Sync>> {
+        self.assign_unique_slug(&mut blog)
+            .await
+            .map_err(|e| format!("Failed to assign slug: {e}"))?;
+        blog.refresh_stats();
         let blog_cloned = blog.clone();
         let partition_key = PartitionKey::from(PARTITION_KEY);
         match self
@@ -44,7 +188,7 @@ impl BlogService {
             .await
         {
             Ok(_) => {
-                logging::console_log(&format!("Created blog in Cosmos DB: {blog_cloned:#?}",));
+                logging::console_log(&format!("Created blog in Cosmos DB: {blog_cloned:#?}"));
                 Ok(blog_cloned)
             }
             Err(e) => {
@@ -93,71 +237,39 @@ impl BlogService {
         }
     }
 
-    /// Retrieves a list of todo items from the Cosmos DB container for a specific todo ID.
+    /// Lists every post regardless of visibility, newest first, optionally only those
+    /// with `visibility`. For admin views only; public lists use
+    /// [`Self::list_published_blog_posts`].
     ///
     /// # Errors
     ///
-    /// Returns an `AzureError` if the query operation fails or if there's an issue
-    /// connecting to the Cosmos DB service.
-    pub async fn list_blog_posts(
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_all_blog_posts(
         &self,
-    ) -> Result<Vec<BlogPost>, Box<dyn std::error::Error + Send + Sync>> {
-        // Use a more explicit query approach
-        let query = "SELECT * FROM c ORDER BY c.created_at DESC";
-        let partition_key = PartitionKey::from(PARTITION_KEY);
+        visibility: Option<Visibility>,
+    ) -> Result<Vec<BlogPost>> {
+        let query = match visibility {
+            Some(visibility) => Query::from(
+                "SELECT * FROM c WHERE c.visibility = @visibility ORDER BY c.created_at DESC",
+            )
+            .with_parameter("@visibility", visibility.as_str())
+            .wrap_err("Failed to build visibility query")?,
+            None => Query::from("SELECT * FROM c ORDER BY c.created_at DESC"),
+        };
 
-        logging::console_log("Starting Cosmos DB query for blog posts...");
-
-        let mut blogs = Vec::new();
-
-        // Create the query stream
-        let query_result = self
-            .client
-            .get_container("blogs")
-            .map_err(|e| {
-                logging::console_error(&format!("Failed to get container for blogs: {e}"));
-                e
-            })?
-            .query_items::<BlogPost>(query, partition_key, None);
-
-        match query_result {
-            Ok(mut query_stream) => {
-                logging::console_log("Query stream created successfully");
-
-                // Process the stream more carefully
-                loop {
-                    match query_stream.try_next().await {
-                        Ok(Some(feed_page)) => {
-                            logging::console_log(&format!(
-                                "Received feed page with {} items",
-                                feed_page.items().len()
-                            ));
-
-                            for item in feed_page.items() {
-                                logging::console_log(&format!("Processing item: {item:#?}"));
-                                blogs.push(item.clone());
-                            }
-                        }
-                        Ok(None) => {
-                            break; // No more pages
-                        }
-                        Err(e) => {
-                            logging::console_error(&format!(
-                                "Error reading from query stream: {e}"
-                            ));
-                            return Err(Box::new(e));
-                        }
-                    }
-                }
-            }
-            Err(e) => {
-                logging::console_error(&format!("Error creating query stream: {e}"));
-                return Err(Box::new(e));
-            }
-        }
+        self.query::<BlogPost>(query).await
+    }
 
-        logging::console_log(&format!("Retrieved {} todos from Cosmos DB", blogs.len()));
-        Ok(blogs)
+    /// Lists every published post, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_published_blog_posts(&self) -> Result<Vec<BlogPost>> {
+        self.query::<BlogPost>(listed_query(format!(
+            "SELECT * FROM c WHERE {LISTED} ORDER BY c.created_at DESC"
+        ))?)
+        .await
     }
 
     /// Deletes a todo item from the Cosmos DB container
@@ -194,9 +306,9 @@ impl BlogService {
     /// - There's an issue connecting to the Cosmos DB service
     /// - The response cannot be parsed
     pub async fn list_latest_blog_posts(&self, limit: i32) -> Result<Vec<BlogPost>> {
-        let query = format!(
-            "SELECT * FROM c WHERE c.published = true ORDER BY c.created_at DESC OFFSET 0 LIMIT {limit}"
-        );
+        let query = listed_query(format!(
+            "SELECT * FROM c WHERE {LISTED} ORDER BY c.created_at DESC OFFSET 0 LIMIT {limit}"
+        ))?;
         let partition_key = PartitionKey::from(PARTITION_KEY);
 
         let mut blogs = Vec::new();
@@ -248,6 +360,600 @@ impl BlogService {
         logging::console_log(&format!("Retrieved {} todos from Cosmos DB", blogs.len()));
         Ok(blogs)
     }
+
+    /// Updates an existing blog post. When the title changes the post gets a new slug
+    /// and the old one is kept in `previous_slugs` so existing links keep working.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the existing post cannot be read, if the taken slugs cannot
+    /// be queried or if the replace operation fails.
+    pub async fn update_blog(&self, mut blog: BlogPost) -> Result<BlogPost> {
+        let existing = self
+            .get_blog_post(&blog.id)
+            .await
+            .wrap_err_with(|| format!("Failed to read blog post {}", blog.id))?;
+
+        blog.created_at = existing.created_at;
+        blog.previous_slugs = existing.previous_slugs.clone();
+        blog.slug = existing.slug.clone();
+
+        if existing.title != blog.title || blog.slug.is_empty() {
+            if !existing.slug.is_empty() {
+                blog.previous_slugs.push(existing.slug.clone());
+            }
+            blog.slug = String::new();
+            self.assign_unique_slug(&mut blog).await?;
+            // Renaming back to an earlier title must not leave a redirect loop behind
+            blog.previous_slugs.retain(|previous| previous != &blog.slug);
+        }
+        blog.refresh_stats();
+        blog.updated_at = Utc::now();
+
+        self.client
+            .get_container("blogs")?
+            .replace_item(
+                PartitionKey::from(PARTITION_KEY),
+                &blog.id,
+                blog.clone(),
+                None,
+            )
+            .await
+            .wrap_err("Failed to replace blog post")?;
+
+        Ok(blog)
+    }
+
+    /// Retrieves the blog post currently published under `slug`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn get_blog_post_by_slug(&self, slug: &str) -> Result<Option<BlogPost>> {
+        let query = Query::from("SELECT * FROM c WHERE c.slug = @slug")
+            .with_parameter("@slug", slug)
+            .wrap_err("Failed to build slug query")?;
+
+        Ok(self.query::<BlogPost>(query).await?.into_iter().next())
+    }
+
+    /// Retrieves the blog post that was previously published under `slug`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn find_blog_post_by_previous_slug(&self, slug: &str) -> Result<Option<BlogPost>> {
+        let query = Query::from("SELECT * FROM c WHERE ARRAY_CONTAINS(c.previous_slugs, @slug)")
+            .with_parameter("@slug", slug)
+            .wrap_err("Failed to build previous slug query")?;
+
+        Ok(self.query::<BlogPost>(query).await?.into_iter().next())
+    }
+
+    /// Counts published posts per tag. Tags that only differ in spelling (e.g. "Rust" and
+    /// "rust") share a slug and are merged under their most common spelling.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_tag_counts(&self) -> Result<Vec<TagCount>> {
+        let tags = self
+            .query::<String>(listed_query(format!(
+                "SELECT VALUE t FROM c JOIN t IN c.tags WHERE {LISTED}"
+            ))?)
+            .await?;
+
+        let mut by_slug: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for tag in tags {
+            *by_slug
+                .entry(slugify(&tag))
+                .or_default()
+                .entry(tag)
+                .or_default() += 1;
+        }
+
+        let mut counts: Vec<TagCount> = by_slug
+            .into_iter()
+            .filter_map(|(slug, spellings)| {
+                let count = spellings.values().sum();
+                let tag = rank_spellings(spellings).into_iter().next()?;
+                Some(TagCount { tag, slug, count })
+            })
+            .collect();
+        counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+
+        Ok(counts)
+    }
+
+    /// Retrieves published posts carrying any of `tags`, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_published_posts_by_tags(&self, tags: &[String]) -> Result<Vec<BlogPost>> {
+        let query = listed_query(format!(
+            "SELECT * FROM c WHERE {LISTED} \
+             AND EXISTS(SELECT VALUE t FROM t IN c.tags WHERE ARRAY_CONTAINS(@tags, t)) \
+             ORDER BY c.created_at DESC"
+        ))?
+        .with_parameter("@tags", tags)
+        .wrap_err("Failed to build tag query")?;
+
+        self.query::<BlogPost>(query).await
+    }
+
+    /// Retrieves the published posts among `ids`, in no particular order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_published_posts_by_ids(&self, ids: &[String]) -> Result<Vec<BlogPost>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = listed_query(format!(
+            "SELECT * FROM c WHERE {LISTED} AND ARRAY_CONTAINS(@ids, c.id)"
+        ))?
+        .with_parameter("@ids", ids)
+        .wrap_err("Failed to build post id query")?;
+
+        self.query::<BlogPost>(query).await
+    }
+
+    /// Records which series `post_id` belongs to and its one-based part number, or
+    /// removes it from its series when `series` is `None`. Unlike [`Self::update_blog`]
+    /// this leaves the slug and `updated_at` untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the post cannot be read or replaced.
+    pub async fn set_series(&self, post_id: &str, series: Option<(&str, u32)>) -> Result<()> {
+        let mut blog = self
+            .get_blog_post(post_id)
+            .await
+            .wrap_err_with(|| format!("Failed to read blog post {post_id}"))?;
+
+        let series_id = series.map(|(id, _)| id.to_string());
+        let series_position = series.map(|(_, position)| position);
+        if blog.series_id == series_id && blog.series_position == series_position {
+            return Ok(());
+        }
+        blog.series_id = series_id;
+        blog.series_position = series_position;
+
+        self.client
+            .get_container("blogs")?
+            .replace_item(PartitionKey::from(PARTITION_KEY), post_id, blog, None)
+            .await
+            .wrap_err("Failed to replace blog post")?;
+
+        Ok(())
+    }
+
+    /// Changes the visibility of `post_id`. Making a draft public or unlisted by hand
+    /// drops its scheduled publish time, so the scheduler doesn't act on it later.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the post cannot be read or replaced.
+    pub async fn set_visibility(&self, post_id: &str, visibility: Visibility) -> Result<BlogPost> {
+        let mut blog = self
+            .get_blog_post(post_id)
+            .await
+            .wrap_err_with(|| format!("Failed to read blog post {post_id}"))?;
+        if blog.visibility == visibility {
+            return Ok(blog);
+        }
+
+        if visibility != Visibility::Draft {
+            blog.publish_at = None;
+        }
+        blog.visibility = visibility;
+        blog.updated_at = Utc::now();
+
+        self.client
+            .get_container("blogs")?
+            .replace_item(
+                PartitionKey::from(PARTITION_KEY),
+                post_id,
+                blog.clone(),
+                None,
+            )
+            .await
+            .wrap_err("Failed to replace blog post")?;
+
+        Ok(blog)
+    }
+
+    /// Shows or hides the table of contents on the page of `post_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the post cannot be read or replaced.
+    pub async fn set_show_toc(&self, post_id: &str, show_toc: bool) -> Result<BlogPost> {
+        let mut blog = self
+            .get_blog_post(post_id)
+            .await
+            .wrap_err_with(|| format!("Failed to read blog post {post_id}"))?;
+        if blog.show_toc == show_toc {
+            return Ok(blog);
+        }
+
+        blog.show_toc = show_toc;
+        blog.updated_at = Utc::now();
+
+        self.client
+            .get_container("blogs")?
+            .replace_item(
+                PartitionKey::from(PARTITION_KEY),
+                post_id,
+                blog.clone(),
+                None,
+            )
+            .await
+            .wrap_err("Failed to replace blog post")?;
+
+        Ok(blog)
+    }
+
+    /// Converts posts stored with the old `published` flag to [`Visibility`]: published
+    /// posts become public, all others drafts. Safe to run repeatedly; returns how many
+    /// posts were converted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the posts cannot be queried or a post cannot be replaced.
+    pub async fn migrate_visibility(&self) -> Result<usize> {
+        let legacy = self
+            .query::<Value>(Query::from(
+                "SELECT * FROM c WHERE NOT IS_DEFINED(c.visibility)",
+            ))
+            .await?;
+
+        let mut migrated = 0;
+        for mut document in legacy {
+            let Some(fields) = document.as_object_mut() else {
+                continue;
+            };
+            let Some(id) = fields.get("id").and_then(Value::as_str).map(str::to_string) else {
+                continue;
+            };
+            let published = fields.remove("published").as_ref().and_then(Value::as_bool);
+            let visibility = if published == Some(true) {
+                Visibility::Public
+            } else {
+                Visibility::Draft
+            };
+            fields.insert("visibility".to_string(), visibility.as_str().into());
+            // Cosmos DB system properties are regenerated on write
+            fields.retain(|key, _| !key.starts_with('_'));
+
+            self.client
+                .get_container("blogs")?
+                .replace_item(PartitionKey::from(PARTITION_KEY), &id, document, None)
+                .await
+                .wrap_err_with(|| format!("Failed to migrate visibility of blog post {id}"))?;
+            migrated += 1;
+        }
+        Ok(migrated)
+    }
+
+    /// Computes and stores `content_stats` for posts saved before stats were stored, so
+    /// listings don't render their Markdown on every request. Safe to run repeatedly;
+    /// returns how many posts were updated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the posts cannot be queried or a post cannot be replaced.
+    pub async fn backfill_content_stats(&self) -> Result<usize> {
+        let missing = self
+            .query::<BlogPost>(Query::from(
+                "SELECT * FROM c WHERE NOT IS_DEFINED(c.content_stats) OR IS_NULL(c.content_stats)",
+            ))
+            .await?;
+
+        let mut backfilled = 0;
+        for mut blog in missing {
+            // Not an edit, so `updated_at` is left alone
+            blog.refresh_stats();
+            let id = blog.id.clone();
+
+            self.client
+                .get_container("blogs")?
+                .replace_item(PartitionKey::from(PARTITION_KEY), &id, blog, None)
+                .await
+                .wrap_err_with(|| format!("Failed to backfill content stats of blog post {id}"))?;
+            backfilled += 1;
+        }
+        Ok(backfilled)
+    }
+
+    /// Publishes scheduled drafts whose publish time has passed and archives expired
+    /// posts, see [`BlogPost::apply_schedule`]. Returns the posts that changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the due posts cannot be queried or a post cannot be replaced.
+    /// Posts changed before the error keep their new state.
+    pub async fn apply_scheduled_changes(&self, now: DateTime<Utc>) -> Result<Vec<BlogPost>> {
+        let query = Query::from(
+            "SELECT * FROM c WHERE (c.visibility = 'draft' AND c.publish_at <= @now) \
+             OR (c.visibility IN ('public', 'unlisted') AND c.unpublish_at <= @now)",
+        )
+        .with_parameter("@now", storage_timestamp(now))
+        .wrap_err("Failed to build schedule query")?;
+
+        let mut changed = Vec::new();
+        for mut blog in self.query::<BlogPost>(query).await? {
+            if !blog.apply_schedule(now) {
+                continue;
+            }
+            self.client
+                .get_container("blogs")?
+                .replace_item(
+                    PartitionKey::from(PARTITION_KEY),
+                    &blog.id,
+                    blog.clone(),
+                    None,
+                )
+                .await
+                .wrap_err_with(|| format!("Failed to apply schedule to blog post {}", blog.id))?;
+            changed.push(blog);
+        }
+        Ok(changed)
+    }
+
+    /// The earliest publish or unpublish time after `now`, if any post is scheduled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn next_scheduled_change(&self, now: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        let query = Query::from(
+            "SELECT c.publish_at, c.unpublish_at FROM c \
+             WHERE c.publish_at > @now OR c.unpublish_at > @now",
+        )
+        .with_parameter("@now", storage_timestamp(now))
+        .wrap_err("Failed to build schedule query")?;
+
+        Ok(self
+            .query::<ScheduleRecord>(query)
+            .await?
+            .into_iter()
+            .flat_map(|record| [record.publish_at, record.unpublish_at])
+            .flatten()
+            .filter(|at| *at > now)
+            .min())
+    }
+
+    /// Counts the published posts matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn count_published_posts(&self, filter: &PostFilter) -> Result<usize> {
+        let counts = self
+            .query::<u64>(filter.query("SELECT VALUE COUNT(1) FROM c", "")?)
+            .await?;
+        // Cosmos DB may return one partial count per page
+        Ok(usize::try_from(counts.into_iter().sum::<u64>()).unwrap_or(usize::MAX))
+    }
+
+    /// Retrieves one page of the published posts matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_published_posts_page(
+        &self,
+        filter: &PostFilter,
+        sort: PostSort,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<BlogPost>> {
+        self.query::<BlogPost>(filter.query(
+            "SELECT * FROM c",
+            &format!("ORDER BY {} OFFSET {offset} LIMIT {limit}", order_by(sort)),
+        )?)
+        .await
+    }
+
+    /// Retrieves every published post matching `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_filtered_posts(
+        &self,
+        filter: &PostFilter,
+        sort: PostSort,
+    ) -> Result<Vec<BlogPost>> {
+        self.query::<BlogPost>(
+            filter.query("SELECT * FROM c", &format!("ORDER BY {}", order_by(sort)))?,
+        )
+        .await
+    }
+
+    /// Counts published posts per month of publication.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_archive_months(&self) -> Result<Vec<ArchiveMonth>> {
+        let groups = self
+            .query::<MonthCount>(listed_query(format!(
+                "SELECT LEFT(c.created_at, 7) AS month, COUNT(1) AS count FROM c \
+                 WHERE {LISTED} GROUP BY LEFT(c.created_at, 7)"
+            ))?)
+            .await?;
+
+        // Groups can be split across result pages, so merge them again
+        let mut counts: HashMap<ArchivePeriod, usize> = HashMap::new();
+        for group in groups {
+            let Some((year, month)) = group.month.split_once('-') else {
+                continue;
+            };
+            let Some(period) = ArchivePeriod::parse(year, Some(month)) else {
+                continue;
+            };
+            *counts.entry(period).or_default() +=
+                usize::try_from(group.count).unwrap_or(usize::MAX);
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|(period, count)| ArchiveMonth { period, count })
+            .collect())
+    }
+
+    /// Lists the years with published posts, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_published_years(&self) -> Result<Vec<i32>> {
+        let years = self
+            .query::<String>(listed_query(format!(
+                "SELECT DISTINCT VALUE LEFT(c.created_at, 4) FROM c WHERE {LISTED}"
+            ))?)
+            .await?;
+
+        let mut years: Vec<i32> = years
+            .iter()
+            .filter_map(|year| year.parse().ok())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        years.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(years)
+    }
+
+    /// Lists every published post as a [`PostSummary`], newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_post_summaries(&self) -> Result<Vec<PostSummary>> {
+        self.query::<PostSummary>(listed_query(format!(
+            "SELECT c.id, c.title, c.tags, c.series_id, c.content_stats, c.created_at FROM c \
+             WHERE {LISTED} ORDER BY c.created_at DESC"
+        ))?)
+        .await
+    }
+
+    /// Lists every published post with its canonical slug and last modification time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn list_sitemap_entries(&self) -> Result<Vec<SitemapEntry>> {
+        self.query::<SitemapEntry>(listed_query(format!(
+            "SELECT c.id, c.slug, c.updated_at FROM c WHERE {LISTED} \
+             ORDER BY c.updated_at DESC"
+        ))?)
+        .await
+    }
+
+    /// Retrieves every distinct spelling of the tags that map to `slug`, most common
+    /// first, so the first one is the display name [`Self::list_tag_counts`] uses too.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub async fn find_tag_spellings(&self, slug: &str) -> Result<Vec<String>> {
+        let tags = self
+            .query::<String>(listed_query(format!(
+                "SELECT VALUE t FROM c JOIN t IN c.tags WHERE {LISTED}"
+            ))?)
+            .await?;
+
+        let mut spellings: HashMap<String, usize> = HashMap::new();
+        for tag in tags.into_iter().filter(|tag| slugify(tag) == slug) {
+            *spellings.entry(tag).or_default() += 1;
+        }
+        Ok(rank_spellings(spellings))
+    }
+
+    /// Gives `blog` a slug derived from its title that no other post uses or used before,
+    /// and that no other `/blog/` route shadows.
+    /// Only posts with a current or earlier slug starting with the base slug can collide
+    /// with it or its `-2`, `-3`, ... variants, so only those are read.
+    async fn assign_unique_slug(&self, blog: &mut BlogPost) -> Result<()> {
+        let base = if blog.slug.is_empty() {
+            slugify(&blog.title)
+        } else {
+            blog.slug.clone()
+        };
+
+        let query = Query::from(
+            "SELECT c.slug, c.previous_slugs FROM c WHERE c.id != @id \
+             AND (STARTSWITH(c.slug, @base) \
+             OR EXISTS(SELECT VALUE s FROM s IN c.previous_slugs WHERE STARTSWITH(s, @base)))",
+        )
+        .with_parameter("@id", blog.id.as_str())
+        .wrap_err("Failed to build slug query")?
+        .with_parameter("@base", base.as_str())
+        .wrap_err("Failed to build slug query")?;
+
+        let taken: HashSet<String> = self
+            .query::<SlugRecord>(query)
+            .await?
+            .into_iter()
+            .flat_map(|record| std::iter::once(record.slug).chain(record.previous_slugs))
+            .filter(|slug| !slug.is_empty())
+            .collect();
+
+        blog.slug = unique_slug(&base, RESERVED_POST_SLUGS, |candidate| {
+            taken.contains(candidate)
+        });
+        Ok(())
+    }
+
+    /// Runs a query against the blogs container and collects every page of results.
+    async fn query<T>(&self, query: Query) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Clone + Send + 'static,
+    {
+        let mut query_stream = self
+            .client
+            .get_container("blogs")?
+            .query_items::<T>(query, PartitionKey::from(PARTITION_KEY), None)
+            .wrap_err("Failed to create query stream")?;
+
+        let mut items = Vec::new();
+        while let Some(feed_page) = query_stream
+            .try_next()
+            .await
+            .wrap_err("Failed to read from query stream")?
+        {
+            items.extend(feed_page.items().iter().cloned());
+        }
+        Ok(items)
+    }
+}
+
+/// `ORDER BY` expression for `sort`.
+fn order_by(sort: PostSort) -> &'static str {
+    match sort {
+        PostSort::Newest => "c.created_at DESC",
+        PostSort::Oldest => "c.created_at ASC",
+        PostSort::Title => "c.title ASC",
+    }
+}
+
+/// Spellings of one tag with their post counts, most common first. Ties go to the
+/// alphabetically first spelling so the display name does not change between requests.
+fn rank_spellings(spellings: HashMap<String, usize>) -> Vec<String> {
+    let mut ranked: Vec<(String, usize)> = spellings.into_iter().collect();
+    ranked.sort_by(|(a_tag, a), (b_tag, b)| b.cmp(a).then_with(|| a_tag.cmp(b_tag)));
+    ranked.into_iter().map(|(tag, _)| tag).collect()
+}
+
+/// Builds a query over [`LISTED`] posts, binding `@now` to the current time.
+fn listed_query(sql: String) -> Result<Query> {
+    Query::from(sql)
+        .with_parameter("@now", storage_timestamp(Utc::now()))
+        .wrap_err("Failed to build listed posts query")
 }
 
 // Global lazy-initialized instance
@@ -290,3 +996,19 @@ pub fn initialize_blogs_db() -> Result<(), Box<dyn std::error::Error + Send + Sy
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranks_the_most_common_spelling_first() {
+        let spellings = HashMap::from([
+            ("rust".to_string(), 1),
+            ("Rust".to_string(), 3),
+            ("RUST".to_string(), 1),
+        ]);
+
+        assert_eq!(rank_spellings(spellings), vec!["Rust", "RUST", "rust"]);
+    }
+}
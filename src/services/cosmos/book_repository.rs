@@ -1,4 +1,5 @@
 use azure_data_cosmos::PartitionKey;
+use chrono::Utc;
 use color_eyre::{eyre::WrapErr, Result};
 use futures::TryStreamExt;
 use leptos::leptos_dom::logging;
@@ -7,6 +8,10 @@ use crate::{
     models::books::{Book, BookCategory},
     services::cosmos::CosmosClientManager,
 };
+
+/// Partition every book is stored in and read from, whatever its category.
+const PARTITION_KEY: &str = "python";
+
 pub struct BookService {
     client: CosmosClientManager,
 }
@@ -80,7 +85,7 @@ impl BookService {
             "SELECT * FROM c WHERE c.category = '{}' ORDER BY c.publication_year DESC",
             category.db_name()
         );
-        let partition_key = PartitionKey::from("python");
+        let partition_key = PartitionKey::from(PARTITION_KEY);
 
         logging::console_log(&format!(
             "Starting Cosmos DB query for books in category: {}",
@@ -124,6 +129,38 @@ impl BookService {
             }
         }
     }
+
+    /// Creates or replaces `book` and stamps it with the current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the container cannot be accessed or the write fails.
+    pub async fn save_book(&self, mut book: Book) -> Result<Book> {
+        book.last_updated = Utc::now();
+
+        self.client
+            .get_container("books")?
+            .upsert_item(PartitionKey::from(PARTITION_KEY), book.clone(), None)
+            .await
+            .wrap_err_with(|| format!("Failed to save book {}", book.id))?;
+
+        Ok(book)
+    }
+
+    /// Deletes the book with `book_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the container cannot be accessed or the delete fails.
+    pub async fn delete_book(&self, book_id: &str) -> Result<()> {
+        self.client
+            .get_container("books")?
+            .delete_item(PartitionKey::from(PARTITION_KEY), book_id, None)
+            .await
+            .wrap_err_with(|| format!("Failed to delete book {book_id}"))?;
+
+        Ok(())
+    }
 }
 
 // Global lazy-initialized instance
@@ -1,5 +1,5 @@
 use azure_core::credentials::Secret;
-use azure_data_cosmos::clients::ContainerClient;
+use azure_data_cosmos::clients::{ContainerClient, DatabaseClient};
 use azure_data_cosmos::CosmosClient;
 use color_eyre::{eyre::WrapErr, Result};
 use std::sync::Arc;
@@ -22,7 +22,7 @@ impl CosmosClientManager {
     pub fn new(config: &AppConfig) -> Result<Self> {
         let client = CosmosClient::with_key(
             &config.cosmos.uri,
-            Secret::from(config.cosmos.primary_key.clone()),
+            Secret::from(config.cosmos.primary_key.expose_secret().to_string()),
             None,
         )
         .wrap_err("Failed to create Cosmos client")?;
@@ -50,6 +50,12 @@ impl CosmosClientManager {
         Ok(container_client)
     }
 
+    /// Gets a client for the configured database.
+    #[must_use]
+    pub fn get_database(&self) -> DatabaseClient {
+        self.client.database_client(&self.database_name)
+    }
+
     #[must_use]
     pub fn list_container_types(&self) -> Vec<String> {
         self.containers.keys().cloned().collect()
@@ -1,6 +1,8 @@
 pub mod blog_repository;
 pub mod book_repository;
 pub mod client;
+pub mod preview_repository;
+pub mod series_repository;
 
 pub use blog_repository::*;
 pub use client::*;
@@ -0,0 +1,137 @@
+use azure_data_cosmos::{PartitionKey, Query};
+use chrono::Utc;
+use color_eyre::{eyre::WrapErr, Result};
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    models::preview::{PreviewLink, PreviewLinkSummary},
+    services::cosmos::{blog_repository::PARTITION_KEY, CosmosClientManager},
+};
+
+const SUMMARY_FIELDS: &str = "c.id, c.post_id, c.post.title AS post_title, \
+     c.post.updated_at AS revision, c.created_at, c.expires_at, c.revoked_at";
+
+pub struct PreviewService {
+    client: CosmosClientManager,
+}
+
+impl PreviewService {
+    /// Creates a new instance of the preview service.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible; returns `Result` to match the other services.
+    pub fn new(client: CosmosClientManager) -> Result<Self> {
+        Ok(Self { client })
+    }
+
+    /// Stores a new preview link.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the link cannot be written.
+    pub async fn create_link(&self, link: PreviewLink) -> Result<PreviewLink> {
+        self.client
+            .get_container("previews")?
+            .create_item(PartitionKey::from(PARTITION_KEY), link.clone(), None)
+            .await
+            .wrap_err("Failed to create preview link")?;
+        Ok(link)
+    }
+
+    /// Retrieves the preview link with `id`, including its post snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn get_link(&self, id: &str) -> Result<Option<PreviewLink>> {
+        let query = Query::from("SELECT * FROM c WHERE c.id = @id")
+            .with_parameter("@id", id)
+            .wrap_err("Failed to build preview link query")?;
+
+        Ok(self.query::<PreviewLink>(query).await?.into_iter().next())
+    }
+
+    /// Every preview link created for the post with `post_id`, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn list_links(&self, post_id: &str) -> Result<Vec<PreviewLinkSummary>> {
+        let query = Query::from(format!(
+            "SELECT {SUMMARY_FIELDS} FROM c WHERE c.post_id = @post_id \
+             ORDER BY c.created_at DESC"
+        ))
+        .with_parameter("@post_id", post_id)
+        .wrap_err("Failed to build preview link list query")?;
+
+        self.query::<PreviewLinkSummary>(query).await
+    }
+
+    /// Revokes the preview link with `id`. Returns `false` if there is no such link.
+    /// Revoking a link twice keeps the original revocation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the link cannot be loaded or written.
+    pub async fn revoke_link(&self, id: &str) -> Result<bool> {
+        let Some(mut link) = self.get_link(id).await? else {
+            return Ok(false);
+        };
+        if link.revoked_at.is_some() {
+            return Ok(true);
+        }
+
+        link.revoked_at = Some(Utc::now());
+        self.client
+            .get_container("previews")?
+            .replace_item(PartitionKey::from(PARTITION_KEY), id, link, None)
+            .await
+            .wrap_err("Failed to revoke preview link")?;
+        Ok(true)
+    }
+
+    /// Runs a query against the previews container and collects every page of results.
+    async fn query<T>(&self, query: Query) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Clone + Send + 'static,
+    {
+        let mut query_stream = self
+            .client
+            .get_container("previews")?
+            .query_items::<T>(query, PartitionKey::from(PARTITION_KEY), None)
+            .wrap_err("Failed to create query stream")?;
+
+        let mut items = Vec::new();
+        while let Some(feed_page) = query_stream
+            .try_next()
+            .await
+            .wrap_err("Failed to read from query stream")?
+        {
+            items.extend(feed_page.items().iter().cloned());
+        }
+        Ok(items)
+    }
+}
+
+// Global lazy-initialized instance
+static PREVIEW_SERVICE: std::sync::LazyLock<Result<PreviewService>> =
+    std::sync::LazyLock::new(|| {
+        use crate::services::config::get_config;
+
+        let client = CosmosClientManager::new(get_config())
+            .wrap_err("Failed to create Cosmos client manager")?;
+        PreviewService::new(client).wrap_err("Failed to create preview service")
+    });
+
+/// Returns a reference to the global preview service instance.
+///
+/// # Errors
+///
+/// Returns an error if the preview service failed to initialize.
+pub fn get_preview_service() -> Result<&'static PreviewService> {
+    PREVIEW_SERVICE
+        .as_ref()
+        .map_err(|e| color_eyre::eyre::eyre!(format!("Failed to get preview service: {e}")))
+}
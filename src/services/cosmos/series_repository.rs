@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+
+use azure_data_cosmos::{PartitionKey, Query};
+use chrono::Utc;
+use color_eyre::{eyre::WrapErr, Result};
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    models::{
+        blog::BlogPost,
+        series::{Series, SeriesNavigation, SeriesPosts},
+    },
+    services::cosmos::{
+        blog_repository::{BlogService, PARTITION_KEY},
+        CosmosClientManager,
+    },
+    utils::slug::{slugify, unique_slug},
+};
+
+pub struct SeriesService {
+    client: CosmosClientManager,
+}
+
+impl SeriesService {
+    /// Creates a new instance of the series service.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible; returns `Result` to match the other services.
+    pub fn new(client: CosmosClientManager) -> Result<Self> {
+        Ok(Self { client })
+    }
+
+    /// Retrieves the series with `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn get_series(&self, id: &str) -> Result<Option<Series>> {
+        let query = Query::from("SELECT * FROM c WHERE c.id = @id")
+            .with_parameter("@id", id)
+            .wrap_err("Failed to build series id query")?;
+
+        Ok(self.query::<Series>(query).await?.into_iter().next())
+    }
+
+    /// Retrieves the series published under `slug`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query cannot be built or executed.
+    pub async fn get_series_by_slug(&self, slug: &str) -> Result<Option<Series>> {
+        let query = Query::from("SELECT * FROM c WHERE c.slug = @slug")
+            .with_parameter("@slug", slug)
+            .wrap_err("Failed to build series slug query")?;
+
+        Ok(self.query::<Series>(query).await?.into_iter().next())
+    }
+
+    /// Creates or replaces `series` and updates `series_id` and `series_position` on
+    /// its posts. Posts that were dropped from the series are detached from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the series cannot be written or a post cannot be updated.
+    pub async fn save_series(&self, mut series: Series, blogs: &BlogService) -> Result<Series> {
+        let previous_post_ids = self
+            .get_series(&series.id)
+            .await?
+            .map(|existing| existing.post_ids)
+            .unwrap_or_default();
+
+        self.assign_unique_slug(&mut series).await?;
+        series.updated_at = Utc::now();
+
+        self.client
+            .get_container("series")?
+            .upsert_item(PartitionKey::from(PARTITION_KEY), series.clone(), None)
+            .await
+            .wrap_err("Failed to save series")?;
+
+        for (index, post_id) in series.post_ids.iter().enumerate() {
+            let position = u32::try_from(index + 1).unwrap_or(u32::MAX);
+            blogs
+                .set_series(post_id, Some((&series.id, position)))
+                .await?;
+        }
+        for post_id in previous_post_ids
+            .iter()
+            .filter(|post_id| !series.post_ids.contains(post_id))
+        {
+            blogs.set_series(post_id, None).await?;
+        }
+
+        Ok(series)
+    }
+
+    /// Navigation between the parts of the series `post` belongs to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the series or its posts cannot be loaded.
+    pub async fn navigation_for(
+        &self,
+        blogs: &BlogService,
+        post: &BlogPost,
+    ) -> Result<Option<SeriesNavigation>> {
+        let Some(series_id) = post.series_id.as_deref() else {
+            return Ok(None);
+        };
+        let Some(series) = self.get_series(series_id).await? else {
+            return Ok(None);
+        };
+
+        let posts = blogs.list_published_posts_by_ids(&series.post_ids).await?;
+        Ok(SeriesNavigation::new(&series, posts, &post.id))
+    }
+
+    /// The series published under `slug` with its published posts in reading order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the series or its posts cannot be loaded.
+    pub async fn series_posts(
+        &self,
+        blogs: &BlogService,
+        slug: &str,
+    ) -> Result<Option<SeriesPosts>> {
+        let Some(series) = self.get_series_by_slug(slug).await? else {
+            return Ok(None);
+        };
+
+        let posts = blogs.list_published_posts_by_ids(&series.post_ids).await?;
+        let posts = series.order_posts(posts);
+        Ok(Some(SeriesPosts { series, posts }))
+    }
+
+    /// Gives `series` a slug derived from its title that no other series uses.
+    async fn assign_unique_slug(&self, series: &mut Series) -> Result<()> {
+        let base = if series.slug.is_empty() {
+            slugify(&series.title)
+        } else {
+            series.slug.clone()
+        };
+
+        // Only slugs starting with the base can collide with it or its numbered variants
+        let query = Query::from(
+            "SELECT VALUE c.slug FROM c WHERE c.id != @id AND STARTSWITH(c.slug, @base)",
+        )
+        .with_parameter("@id", series.id.as_str())
+        .wrap_err("Failed to build slug query")?
+        .with_parameter("@base", base.as_str())
+        .wrap_err("Failed to build slug query")?;
+
+        let taken: HashSet<String> = self.query::<String>(query).await?.into_iter().collect();
+
+        series.slug = unique_slug(&base, &[], |candidate| taken.contains(candidate));
+        Ok(())
+    }
+
+    /// Runs a query against the series container and collects every page of results.
+    async fn query<T>(&self, query: Query) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned + Clone + Send + 'static,
+    {
+        let mut query_stream = self
+            .client
+            .get_container("series")?
+            .query_items::<T>(query, PartitionKey::from(PARTITION_KEY), None)
+            .wrap_err("Failed to create query stream")?;
+
+        let mut items = Vec::new();
+        while let Some(feed_page) = query_stream
+            .try_next()
+            .await
+            .wrap_err("Failed to read from query stream")?
+        {
+            items.extend(feed_page.items().iter().cloned());
+        }
+        Ok(items)
+    }
+}
+
+// Global lazy-initialized instance
+static SERIES_SERVICE: std::sync::LazyLock<Result<SeriesService>> =
+    std::sync::LazyLock::new(|| {
+        use crate::services::config::get_config;
+
+        let client = CosmosClientManager::new(get_config())
+            .wrap_err("Failed to create Cosmos client manager")?;
+        SeriesService::new(client).wrap_err("Failed to create series service")
+    });
+
+/// Returns a reference to the global series service instance.
+///
+/// # Errors
+///
+/// Returns an error if the series service failed to initialize.
+pub fn get_series_service() -> Result<&'static SeriesService> {
+    SERIES_SERVICE
+        .as_ref()
+        .map_err(|e| color_eyre::eyre::eyre!(format!("Failed to get series service: {e}")))
+}
@@ -0,0 +1,58 @@
+//! In-process notifications about content changes.
+//!
+//! Producers call [`publish`]; anything that caches derived data (related posts,
+//! search indexes) calls [`subscribe`] and refreshes itself when an event arrives.
+
+use std::sync::LazyLock;
+
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before the slowest one starts missing some.
+const CHANNEL_CAPACITY: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentEvent {
+    /// A post became public and shows up in lists.
+    PostPublished { id: String },
+    /// A post left the public lists: it became a draft, unlisted or archived.
+    PostUnpublished { id: String },
+    /// A post was created or edited without being published or unpublished.
+    PostUpdated { id: String },
+    /// A post was deleted.
+    PostDeleted { id: String },
+    /// A series was created or its parts changed.
+    SeriesUpdated { id: String },
+    /// A book was added or edited.
+    BookUpdated { id: String },
+    /// A book was deleted.
+    BookDeleted { id: String },
+}
+
+impl ContentEvent {
+    /// The event for a write that left post `id` listed or not, depending on whether
+    /// it was listed before.
+    #[must_use]
+    pub fn post_changed(id: String, was_listed: bool, is_listed: bool) -> Self {
+        match (was_listed, is_listed) {
+            (false, true) => Self::PostPublished { id },
+            (true, false) => Self::PostUnpublished { id },
+            _ => Self::PostUpdated { id },
+        }
+    }
+}
+
+static CHANNEL: LazyLock<broadcast::Sender<ContentEvent>> =
+    LazyLock::new(|| broadcast::channel(CHANNEL_CAPACITY).0);
+
+/// Sends `event` to every current subscriber. Events sent while nobody listens are dropped.
+pub fn publish(event: ContentEvent) {
+    // An error only means there are no subscribers right now
+    let _ = CHANNEL.send(event);
+}
+
+/// Receives every event published from now on. A receiver that falls behind gets
+/// `RecvError::Lagged` and should treat it as "something changed".
+#[must_use]
+pub fn subscribe() -> broadcast::Receiver<ContentEvent> {
+    CHANNEL.subscribe()
+}
@@ -1,2 +1,17 @@
+#[cfg(feature = "ssr")]
+pub mod admin;
 pub mod config;
 pub mod cosmos;
+#[cfg(feature = "ssr")]
+pub mod events;
+#[cfg(feature = "ssr")]
+pub mod preflight;
+#[cfg(feature = "ssr")]
+pub mod preview;
+#[cfg(feature = "ssr")]
+pub mod related_posts;
+#[cfg(feature = "ssr")]
+pub mod scheduler;
+#[cfg(feature = "ssr")]
+pub mod search;
+pub mod secret;
@@ -0,0 +1,314 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use azure_core::http::StatusCode;
+use leptos::config::LeptosOptions;
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::services::config::{try_get_config, AppConfig};
+use crate::services::cosmos::CosmosClientManager;
+
+/// How long a single Cosmos DB probe may take before it is reported as unreachable.
+const COSMOS_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Container types the application cannot run without.
+const REQUIRED_CONTAINERS: &[&str] = &["blogs", "books", "series", "previews"];
+
+/// Files from `public/` that the `App` shell links to directly.
+const REQUIRED_PUBLIC_ASSETS: &[&str] = &[
+    "assets/main.css",
+    "assets/syntax.css",
+    "images/dev-logo-64x64.png",
+];
+
+/// Aggregated result of the startup checks, rendered by `miette` with one entry per failure.
+#[derive(Debug, Error, Diagnostic)]
+#[error("Preflight checks failed with {} problem(s)", .failures.len())]
+#[diagnostic(
+    code(preflight::failed),
+    help("Fix the problems listed below and start the server again. Run with `--check` to only re-run these checks.")
+)]
+pub struct PreflightReport {
+    #[related]
+    pub failures: Vec<PreflightFailure>,
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum PreflightFailure {
+    #[error("Application configuration could not be loaded: {reason}")]
+    #[diagnostic(
+        code(preflight::config),
+        help("Set COSMOS_DB_URI, COSMOS_DB_DATABASE and COSMOS_DB_KEY in the environment or in the `.env` file next to the binary.")
+    )]
+    Config { reason: String },
+
+    #[error("Container type '{kind}' is not configured")]
+    #[diagnostic(
+        code(preflight::container_not_configured),
+        help("Add a '{kind}' entry to the container map in `AppConfig::from_env`.")
+    )]
+    ContainerNotConfigured { kind: String },
+
+    #[error("Cosmos DB at {uri} could not be reached: {reason}")]
+    #[diagnostic(
+        code(preflight::cosmos_unreachable),
+        help("Check that COSMOS_DB_URI is correct, that the account (or the local emulator) is running and that this host is allowed by the account firewall.")
+    )]
+    CosmosUnreachable { uri: String, reason: String },
+
+    #[error("Cosmos DB at {uri} rejected the configured credentials")]
+    #[diagnostic(
+        code(preflight::cosmos_unauthorized),
+        help("COSMOS_DB_KEY must be the primary or secondary key of the account. Copy it again from the Azure portal under 'Keys'.")
+    )]
+    CosmosUnauthorized { uri: String },
+
+    #[error("Database '{database}' does not exist")]
+    #[diagnostic(
+        code(preflight::database_missing),
+        help("Create the database '{database}' in the Cosmos DB account or point COSMOS_DB_DATABASE at an existing one.")
+    )]
+    DatabaseMissing { database: String },
+
+    #[error("Container '{name}' for {kind} does not exist in database '{database}'")]
+    #[diagnostic(
+        code(preflight::container_missing),
+        help("Create the container '{name}' with partition key '/{partition_key}', or set the matching COSMOS_*_CONTAINER_NAME variable to an existing container.")
+    )]
+    ContainerMissing {
+        kind: String,
+        name: String,
+        database: String,
+        partition_key: String,
+    },
+
+    #[error("SITE_URL '{url}' is not a valid public base URL: {reason}")]
+    #[diagnostic(
+        code(preflight::site_url_invalid),
+        help("Set SITE_URL to the scheme and host the site is served from, e.g. `https://blog.example.com`, without a path.")
+    )]
+    SiteUrlInvalid { url: String, reason: String },
+
+    #[error("SITE_URL points at {url} while search engines may index the site")]
+    #[diagnostic(
+        code(preflight::site_url_local),
+        help("Feeds, the sitemap, canonical links and preview links would all point at this machine. Set SITE_URL to the public address, or set ROBOTS_ALLOW_INDEXING=false for a non-public deployment.")
+    )]
+    SiteUrlLocal { url: String },
+
+    #[error("Site root directory {} does not exist", .path.display())]
+    #[diagnostic(
+        code(preflight::site_root_missing),
+        help("Build the site with `cargo leptos build --release`, or set LEPTOS_SITE_ROOT to the directory that contains the built site.")
+    )]
+    SiteRootMissing { path: PathBuf },
+
+    #[error("Client bundle file {} is missing", .path.display())]
+    #[diagnostic(
+        code(preflight::bundle_missing),
+        help("The /pkg bundle is produced by `cargo leptos build`. Rebuild the site and make sure LEPTOS_SITE_PKG_DIR and LEPTOS_OUTPUT_NAME match the build.")
+    )]
+    BundleMissing { path: PathBuf },
+
+    #[error("Public asset {} is missing", .path.display())]
+    #[diagnostic(
+        code(preflight::public_asset_missing),
+        help("Files from `public/` are copied into the site root by cargo-leptos (`assets-dir`). Copy the directory manually when deploying without cargo-leptos.")
+    )]
+    PublicAssetMissing { path: PathBuf },
+}
+
+/// Runs every startup check and collects all failures instead of stopping at the first one.
+///
+/// # Errors
+///
+/// Returns a [`PreflightReport`] listing each failed check together with a suggested fix.
+pub async fn run_preflight(leptos_options: &LeptosOptions) -> Result<(), PreflightReport> {
+    let mut failures = Vec::new();
+
+    match try_get_config() {
+        Ok(config) => {
+            failures.extend(check_site_url(config));
+            failures.extend(check_cosmos(config).await);
+        }
+        Err(e) => failures.push(PreflightFailure::Config {
+            reason: e.to_string(),
+        }),
+    }
+
+    failures.extend(check_static_assets(leptos_options));
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(PreflightReport { failures })
+    }
+}
+
+/// Every absolute link the site hands out is built from `SITE_URL`, so it must be a bare
+/// origin, and release builds that search engines may index must not point at localhost.
+fn check_site_url(config: &AppConfig) -> Option<PreflightFailure> {
+    let url = &config.site.base_url;
+    let invalid = |reason: &str| {
+        Some(PreflightFailure::SiteUrlInvalid {
+            url: url.clone(),
+            reason: reason.to_string(),
+        })
+    };
+
+    let Some(authority) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return invalid("it must start with http:// or https://");
+    };
+    if authority.is_empty() {
+        return invalid("the host is missing");
+    }
+    if authority.contains(['/', '?', '#']) {
+        return invalid("it must not contain a path, query or fragment");
+    }
+
+    let host = authority
+        .rsplit_once(':')
+        .map_or(authority, |(host, _)| host);
+    let is_local = matches!(host, "localhost" | "127.0.0.1" | "0.0.0.0" | "[::1]");
+    if is_local && config.robots.allow_indexing && !cfg!(debug_assertions) {
+        return Some(PreflightFailure::SiteUrlLocal { url: url.clone() });
+    }
+
+    None
+}
+
+async fn check_cosmos(config: &AppConfig) -> Vec<PreflightFailure> {
+    let mut failures = Vec::new();
+    let uri = config.cosmos.uri.clone();
+
+    let manager = match CosmosClientManager::new(config) {
+        Ok(manager) => manager,
+        Err(e) => {
+            failures.push(PreflightFailure::CosmosUnreachable {
+                uri,
+                reason: e.to_string(),
+            });
+            return failures;
+        }
+    };
+
+    // The database probe doubles as the connectivity and credential check.
+    let database = manager.get_database();
+    match tokio::time::timeout(COSMOS_PROBE_TIMEOUT, database.read(None)).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => {
+            failures.push(match e.http_status() {
+                Some(StatusCode::NotFound) => PreflightFailure::DatabaseMissing {
+                    database: config.cosmos.database_name.clone(),
+                },
+                Some(StatusCode::Unauthorized | StatusCode::Forbidden) => {
+                    PreflightFailure::CosmosUnauthorized { uri }
+                }
+                _ => PreflightFailure::CosmosUnreachable {
+                    uri,
+                    reason: e.to_string(),
+                },
+            });
+            return failures;
+        }
+        Err(_) => {
+            failures.push(PreflightFailure::CosmosUnreachable {
+                uri,
+                reason: format!(
+                    "no response within {} seconds",
+                    COSMOS_PROBE_TIMEOUT.as_secs()
+                ),
+            });
+            return failures;
+        }
+    }
+
+    for kind in REQUIRED_CONTAINERS {
+        let Some(container_config) = config.get_container_config(kind) else {
+            failures.push(PreflightFailure::ContainerNotConfigured {
+                kind: (*kind).to_string(),
+            });
+            continue;
+        };
+
+        let Ok(container) = manager.get_container(kind) else {
+            failures.push(PreflightFailure::ContainerNotConfigured {
+                kind: (*kind).to_string(),
+            });
+            continue;
+        };
+
+        match tokio::time::timeout(COSMOS_PROBE_TIMEOUT, container.read(None)).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) if e.http_status() == Some(StatusCode::NotFound) => {
+                failures.push(PreflightFailure::ContainerMissing {
+                    kind: (*kind).to_string(),
+                    name: container_config.name.clone(),
+                    database: config.cosmos.database_name.clone(),
+                    partition_key: container_config.partition_key.clone(),
+                });
+            }
+            Ok(Err(e)) => failures.push(PreflightFailure::CosmosUnreachable {
+                uri: uri.clone(),
+                reason: e.to_string(),
+            }),
+            Err(_) => failures.push(PreflightFailure::CosmosUnreachable {
+                uri: uri.clone(),
+                reason: format!(
+                    "reading container '{}' timed out after {} seconds",
+                    container_config.name,
+                    COSMOS_PROBE_TIMEOUT.as_secs()
+                ),
+            }),
+        }
+    }
+
+    failures
+}
+
+fn check_static_assets(options: &LeptosOptions) -> Vec<PreflightFailure> {
+    let site_root = Path::new(options.site_root.as_ref());
+    if !site_root.is_dir() {
+        return vec![PreflightFailure::SiteRootMissing {
+            path: site_root.to_path_buf(),
+        }];
+    }
+
+    let mut failures = Vec::new();
+    let pkg_dir = site_root.join(options.site_pkg_dir.as_ref());
+    let output_name = options.output_name.as_ref();
+
+    if options.hash_files {
+        // Hashed bundles carry a content hash in their names, so only check that a build exists.
+        let has_wasm = std::fs::read_dir(&pkg_dir).is_ok_and(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .any(|entry| entry.path().extension().is_some_and(|ext| ext == "wasm"))
+        });
+        if !has_wasm {
+            failures.push(PreflightFailure::BundleMissing {
+                path: pkg_dir.join(format!("{output_name}.*.wasm")),
+            });
+        }
+    } else {
+        for extension in ["js", "wasm", "css"] {
+            let path = pkg_dir.join(format!("{output_name}.{extension}"));
+            if !path.is_file() {
+                failures.push(PreflightFailure::BundleMissing { path });
+            }
+        }
+    }
+
+    for asset in REQUIRED_PUBLIC_ASSETS {
+        let path = site_root.join(asset);
+        if !path.is_file() {
+            failures.push(PreflightFailure::PublicAssetMissing { path });
+        }
+    }
+
+    failures
+}
@@ -0,0 +1,223 @@
+//! Signed tokens for draft preview links.
+//!
+//! A token names a stored [`PreviewLink`](crate::models::preview::PreviewLink) and
+//! its expiry, signed with HMAC-SHA256 using the admin signing keys. The signature
+//! stops anyone from guessing or extending links; revocation is checked against the
+//! stored link, so a leaked link can be switched off before it expires.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::services::secret::SecretString;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// How long a preview link stays valid unless another lifetime is requested.
+pub const DEFAULT_PREVIEW_TTL: Duration = Duration::hours(72);
+/// Upper bound for requested lifetimes, so no preview link lives on indefinitely.
+pub const MAX_PREVIEW_TTL: Duration = Duration::days(30);
+
+/// Keeps preview signatures from being valid for anything else signed with the same keys.
+const SIGNATURE_CONTEXT: &str = "preview-link";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PreviewTokenError {
+    #[error("No signing key is configured")]
+    NoSigningKey,
+    #[error("Preview token is malformed")]
+    Malformed,
+    #[error("Preview token signature is invalid")]
+    InvalidSignature,
+    #[error("Preview link has expired")]
+    Expired,
+}
+
+/// Signs a token for the preview link `link_id` that expires at `expires_at`.
+/// The first of `keys` signs.
+///
+/// # Errors
+///
+/// Returns [`PreviewTokenError::NoSigningKey`] if `keys` is empty.
+pub fn sign_preview_token(
+    link_id: &str,
+    expires_at: DateTime<Utc>,
+    keys: &[SecretString],
+) -> Result<String, PreviewTokenError> {
+    let key = keys.first().ok_or(PreviewTokenError::NoSigningKey)?;
+    let payload = format!("{link_id}.{}", expires_at.timestamp());
+    let signature = mac(key, &payload).finalize().into_bytes();
+    Ok(format!("{payload}.{}", to_hex(&signature)))
+}
+
+/// Checks the signature and expiry of `token` and returns the id of the preview link
+/// it names. Any of `keys` may have signed the token, so keys can be rotated.
+///
+/// # Errors
+///
+/// Returns an error if the token is malformed, no key signed it or it has expired.
+pub fn verify_preview_token(
+    token: &str,
+    now: DateTime<Utc>,
+    keys: &[SecretString],
+) -> Result<String, PreviewTokenError> {
+    if keys.is_empty() {
+        return Err(PreviewTokenError::NoSigningKey);
+    }
+
+    let (payload, signature) = token.rsplit_once('.').ok_or(PreviewTokenError::Malformed)?;
+    let (link_id, expires_at) = payload
+        .split_once('.')
+        .ok_or(PreviewTokenError::Malformed)?;
+    let signature = from_hex(signature).ok_or(PreviewTokenError::Malformed)?;
+    if link_id.is_empty() {
+        return Err(PreviewTokenError::Malformed);
+    }
+
+    // `verify_slice` compares in constant time
+    if !keys
+        .iter()
+        .any(|key| mac(key, payload).verify_slice(&signature).is_ok())
+    {
+        return Err(PreviewTokenError::InvalidSignature);
+    }
+
+    let expires_at = expires_at
+        .parse::<i64>()
+        .ok()
+        .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0))
+        .ok_or(PreviewTokenError::Malformed)?;
+    if expires_at <= now {
+        return Err(PreviewTokenError::Expired);
+    }
+
+    Ok(link_id.to_string())
+}
+
+fn mac(key: &SecretString, payload: &str) -> HmacSha256 {
+    // HMAC accepts keys of any length, so this never fails
+    let mut mac = HmacSha256::new_from_slice(key.expose_secret().as_bytes())
+        .unwrap_or_else(|_| unreachable!("HMAC keys can have any length"));
+    mac.update(SIGNATURE_CONTEXT.as_bytes());
+    mac.update(b":");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINK_ID: &str = "0b5f4c8e-3d1a-4f4e-9a57-2f1d7c0a9e11";
+
+    fn keys(values: &[&str]) -> Vec<SecretString> {
+        values
+            .iter()
+            .map(|value| SecretString::new(*value))
+            .collect()
+    }
+
+    fn in_one_hour() -> DateTime<Utc> {
+        Utc::now() + Duration::hours(1)
+    }
+
+    #[test]
+    fn round_trips_the_link_id() {
+        let keys = keys(&["current"]);
+        let token = sign_preview_token(LINK_ID, in_one_hour(), &keys);
+        let Ok(token) = token else {
+            panic!("signing with a key should succeed");
+        };
+
+        assert_eq!(
+            verify_preview_token(&token, Utc::now(), &keys),
+            Ok(LINK_ID.to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_tampered_tokens() {
+        let keys = keys(&["current"]);
+        let token = sign_preview_token(LINK_ID, in_one_hour(), &keys).unwrap_or_default();
+
+        let (payload, signature) = token.rsplit_once('.').unwrap_or_default();
+        let (link_id, expires_at) = payload.split_once('.').unwrap_or_default();
+        let extended = expires_at.parse::<i64>().unwrap_or_default() + 86_400;
+        let tampered = [
+            format!("{link_id}.{extended}.{signature}"),
+            format!("other-link.{expires_at}.{signature}"),
+            format!("{payload}.{}", "0".repeat(signature.len())),
+        ];
+
+        for token in tampered {
+            assert_eq!(
+                verify_preview_token(&token, Utc::now(), &keys),
+                Err(PreviewTokenError::InvalidSignature),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_expired_tokens() {
+        let keys = keys(&["current"]);
+        let expires_at = Utc::now() - Duration::minutes(1);
+        let token = sign_preview_token(LINK_ID, expires_at, &keys).unwrap_or_default();
+
+        assert_eq!(
+            verify_preview_token(&token, Utc::now(), &keys),
+            Err(PreviewTokenError::Expired)
+        );
+    }
+
+    #[test]
+    fn verifies_with_rotated_keys_only_while_configured() {
+        let old = keys(&["previous"]);
+        let token = sign_preview_token(LINK_ID, in_one_hour(), &old).unwrap_or_default();
+
+        assert!(verify_preview_token(&token, Utc::now(), &keys(&["current", "previous"])).is_ok());
+        assert_eq!(
+            verify_preview_token(&token, Utc::now(), &keys(&["current"])),
+            Err(PreviewTokenError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_tokens_and_missing_keys() {
+        let keys = keys(&["current"]);
+        for token in ["", "no-dots", "a.b", ".1.00", "a.1.xyz", "a.1.0"] {
+            assert_eq!(
+                verify_preview_token(token, Utc::now(), &keys),
+                Err(PreviewTokenError::Malformed),
+                "{token}"
+            );
+        }
+
+        assert_eq!(
+            sign_preview_token(LINK_ID, in_one_hour(), &[]),
+            Err(PreviewTokenError::NoSigningKey)
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! "Related posts" recommendations for the post page.
+//!
+//! Every published post is scored against the current one by tag overlap, a shared
+//! series and TF-IDF similarity of titles and excerpts. Results are cached per post
+//! for [`CACHE_TTL`], so the full scan runs at most once per post in that window.
+//! Any content change clears the cache right away.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+use color_eyre::Result;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    models::blog::BlogPost,
+    services::{
+        cosmos::blog_repository::{BlogService, PostSummary},
+        events,
+    },
+    utils::{slug::slugify, text::terms},
+};
+
+/// At most this many related posts are shown.
+pub const MAX_RELATED_POSTS: usize = 5;
+/// When fewer posts are related at all, the list is topped up with the newest posts.
+pub const MIN_RELATED_POSTS: usize = 3;
+
+const TAG_WEIGHT: f64 = 0.5;
+const SERIES_WEIGHT: f64 = 0.3;
+const TEXT_WEIGHT: f64 = 0.2;
+
+const CACHE_TTL: Duration = Duration::from_mins(15);
+
+struct CacheEntry {
+    computed_at: Instant,
+    post_ids: Vec<String>,
+}
+
+static CACHE: LazyLock<RwLock<HashMap<String, CacheEntry>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Published posts related to the post with `post_id`, most related first.
+///
+/// # Errors
+///
+/// Returns an error if the posts cannot be loaded.
+pub async fn find_related_posts(blogs: &BlogService, post_id: &str) -> Result<Vec<BlogPost>> {
+    let post_ids = if let Some(post_ids) = cached_post_ids(post_id) {
+        post_ids
+    } else {
+        let summaries = blogs.list_post_summaries().await?;
+        let post_ids = related_post_ids(post_id, &summaries);
+        store_post_ids(post_id, post_ids.clone());
+        post_ids
+    };
+
+    let mut posts = blogs.list_published_posts_by_ids(&post_ids).await?;
+    posts.sort_by_key(|post| post_ids.iter().position(|id| id == &post.id));
+    Ok(posts)
+}
+
+fn cached_post_ids(post_id: &str) -> Option<Vec<String>> {
+    let cache = CACHE.read().ok()?;
+    cache
+        .get(post_id)
+        .filter(|entry| entry.computed_at.elapsed() < CACHE_TTL)
+        .map(|entry| entry.post_ids.clone())
+}
+
+fn store_post_ids(post_id: &str, post_ids: Vec<String>) {
+    if let Ok(mut cache) = CACHE.write() {
+        cache.retain(|_, entry| entry.computed_at.elapsed() < CACHE_TTL);
+        cache.insert(
+            post_id.to_string(),
+            CacheEntry {
+                computed_at: Instant::now(),
+                post_ids,
+            },
+        );
+    }
+}
+
+/// Clears the cache on every content change, so recommendations never point at
+/// hidden or deleted posts or miss new ones for the rest of the TTL.
+pub fn spawn_cache_invalidation() {
+    let mut receiver = events::subscribe();
+    tokio::spawn(async move {
+        // Lagging behind only means several changes arrived at once
+        while let Ok(_) | Err(RecvError::Lagged(_)) = receiver.recv().await {
+            if let Ok(mut cache) = CACHE.write() {
+                cache.clear();
+            }
+        }
+    });
+}
+
+/// Ids of the posts in `summaries` most related to `post_id`, best match first.
+/// `summaries` is expected newest first, which also breaks ties in favor of newer posts.
+#[must_use]
+pub fn related_post_ids(post_id: &str, summaries: &[PostSummary]) -> Vec<String> {
+    let Some(current) = summaries.iter().position(|summary| summary.id == post_id) else {
+        return Vec::new();
+    };
+
+    let vectors = tf_idf_vectors(summaries);
+    let tags: Vec<HashSet<String>> = summaries
+        .iter()
+        .map(|summary| summary.tags.iter().map(|tag| slugify(tag)).collect())
+        .collect();
+
+    let mut scored: Vec<(usize, f64)> = summaries
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != current)
+        .map(|(index, summary)| {
+            let shared_series =
+                summary.series_id.is_some() && summary.series_id == summaries[current].series_id;
+            let score = TAG_WEIGHT * jaccard(&tags[current], &tags[index])
+                + SERIES_WEIGHT * if shared_series { 1.0 } else { 0.0 }
+                + TEXT_WEIGHT * cosine(&vectors[current], &vectors[index]);
+            (index, score)
+        })
+        .collect();
+    // Stable sort keeps the newest-first order for equal scores
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    let mut related: Vec<usize> = scored
+        .iter()
+        .filter(|(_, score)| *score > 0.0)
+        .map(|(index, _)| *index)
+        .take(MAX_RELATED_POSTS)
+        .collect();
+    if related.len() < MIN_RELATED_POSTS {
+        // Indices follow `summaries`, so ascending means newest first
+        let mut newest: Vec<usize> = scored
+            .iter()
+            .map(|(index, _)| *index)
+            .filter(|index| !related.contains(index))
+            .collect();
+        newest.sort_unstable();
+        related.extend(newest.into_iter().take(MIN_RELATED_POSTS - related.len()));
+    }
+
+    related
+        .into_iter()
+        .map(|index| summaries[index].id.clone())
+        .collect()
+}
+
+/// Share of tags two posts have in common.
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    count_as_f64(a.intersection(b).count()) / count_as_f64(union)
+}
+
+/// TF-IDF weighted term vectors of the title and excerpt of every post.
+fn tf_idf_vectors(summaries: &[PostSummary]) -> Vec<HashMap<String, f64>> {
+    let documents: Vec<Vec<String>> = summaries
+        .iter()
+        .map(|summary| {
+            let excerpt = summary
+                .content_stats
+                .as_ref()
+                .map(|stats| stats.excerpt.as_str())
+                .unwrap_or_default();
+            terms(&format!("{} {excerpt}", summary.title))
+        })
+        .collect();
+
+    let mut document_frequency: HashMap<&str, usize> = HashMap::new();
+    for document in &documents {
+        let unique: HashSet<&str> = document.iter().map(String::as_str).collect();
+        for term in unique {
+            *document_frequency.entry(term).or_default() += 1;
+        }
+    }
+
+    let document_count = count_as_f64(documents.len());
+    documents
+        .iter()
+        .map(|document| {
+            let mut term_counts: HashMap<&str, usize> = HashMap::new();
+            for term in document {
+                *term_counts.entry(term).or_default() += 1;
+            }
+            let length = count_as_f64(document.len().max(1));
+            term_counts
+                .into_iter()
+                .map(|(term, count)| {
+                    let frequency = document_frequency.get(term).copied().unwrap_or(1);
+                    // Smoothed so terms found in every post still weigh a little
+                    let idf = (document_count / count_as_f64(frequency)).ln() + 1.0;
+                    (term.to_string(), count_as_f64(count) / length * idf)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn cosine(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(term, weight)| b.get(term).map(|other| weight * other))
+        .sum();
+    let norm = |vector: &HashMap<String, f64>| vector.values().map(|w| w * w).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms > 0.0 {
+        dot / norms
+    } else {
+        0.0
+    }
+}
+
+/// Post and term counts stay far below 2^52, so the conversion is exact.
+#[allow(clippy::cast_precision_loss)]
+fn count_as_f64(count: usize) -> f64 {
+    count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Titles share no words, so only tags and series count.
+    fn summary(id: &str, tags: &[&str], series_id: Option<&str>) -> PostSummary {
+        PostSummary {
+            id: id.to_string(),
+            title: format!("Title{id}"),
+            tags: tags.iter().map(|tag| (*tag).to_string()).collect(),
+            series_id: series_id.map(str::to_string),
+            content_stats: None,
+            created_at: chrono::DateTime::default(),
+        }
+    }
+
+    #[test]
+    fn ranks_by_tag_overlap_and_tops_up_with_newest() {
+        let summaries = [
+            summary("one", &["Rust", "async"], None),
+            summary("two", &["python"], None),
+            summary("three", &["rust"], None),
+            summary("four", &["rust", "Async"], None),
+        ];
+
+        // "four" shares every tag and "three" one of two; "two" only fills the minimum
+        assert_eq!(
+            related_post_ids("one", &summaries),
+            vec!["four", "three", "two"]
+        );
+    }
+
+    #[test]
+    fn shared_series_outweighs_a_partial_tag_match() {
+        let summaries = [
+            summary("one", &["rust"], Some("series")),
+            summary("two", &["rust", "wasm", "web"], None),
+            summary("three", &[], Some("series")),
+            summary("four", &[], Some("other")),
+        ];
+
+        assert_eq!(
+            related_post_ids("one", &summaries),
+            vec!["three", "two", "four"]
+        );
+    }
+
+    #[test]
+    fn equal_scores_keep_newest_first() {
+        let summaries = [
+            summary("one", &["rust"], None),
+            summary("newer", &["rust"], None),
+            summary("older", &["rust"], None),
+        ];
+
+        assert_eq!(related_post_ids("one", &summaries), vec!["newer", "older"]);
+    }
+
+    #[test]
+    fn unrelated_posts_fall_back_to_the_newest() {
+        let summaries: Vec<PostSummary> = ["one", "two", "three", "four", "five"]
+            .iter()
+            .map(|id| summary(id, &[], None))
+            .collect();
+
+        assert_eq!(
+            related_post_ids("three", &summaries).len(),
+            MIN_RELATED_POSTS
+        );
+        assert_eq!(
+            related_post_ids("three", &summaries),
+            vec!["one", "two", "four"]
+        );
+    }
+
+    #[test]
+    fn caps_the_number_of_related_posts() {
+        let mut summaries = vec![summary("current", &["rust"], None)];
+        summaries.extend((0..8).map(|index| summary(&format!("post{index}"), &["rust"], None)));
+
+        assert_eq!(
+            related_post_ids("current", &summaries).len(),
+            MAX_RELATED_POSTS
+        );
+    }
+
+    #[test]
+    fn unknown_posts_have_no_related_posts() {
+        let summaries = [
+            summary("one", &["rust"], None),
+            summary("two", &["rust"], None),
+        ];
+
+        assert!(related_post_ids("missing", &summaries).is_empty());
+    }
+}
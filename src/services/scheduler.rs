@@ -0,0 +1,165 @@
+//! Background task that publishes scheduled drafts and archives expired posts.
+//!
+//! The task sleeps until the next scheduled change, but never longer than
+//! [`MAX_SLEEP`] so posts scheduled in the meantime are picked up too.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use leptos::logging;
+
+use crate::{
+    models::blog::BlogPost,
+    services::{
+        cosmos::blog_repository::get_blog_service,
+        events::{self, ContentEvent},
+    },
+};
+
+const MAX_SLEEP: Duration = Duration::from_mins(1);
+
+/// Starts the scheduler on the current Tokio runtime.
+pub fn spawn_publish_scheduler() {
+    tokio::spawn(async {
+        loop {
+            let sleep = run_scheduled_changes().await;
+            tokio::time::sleep(sleep).await;
+        }
+    });
+}
+
+/// Applies every change that is due and returns how long to sleep until the next one.
+async fn run_scheduled_changes() -> Duration {
+    let blog_service = match get_blog_service() {
+        Ok(blog_service) => blog_service,
+        Err(e) => {
+            logging::error!("Publish scheduler cannot reach blog storage: {}", e);
+            return MAX_SLEEP;
+        }
+    };
+
+    let now = Utc::now();
+    match blog_service.apply_scheduled_changes(now).await {
+        Ok(changed) => {
+            for post in changed {
+                logging::log!(
+                    "Scheduler made post '{}' {}",
+                    post.title,
+                    post.visibility.as_str()
+                );
+                events::publish(schedule_event(post));
+            }
+        }
+        Err(e) => logging::error!("Failed to apply scheduled changes: {:?}", e),
+    }
+
+    match blog_service.next_scheduled_change(now).await {
+        Ok(next) => sleep_until(next, Utc::now()),
+        Err(e) => {
+            logging::error!("Failed to look up the next scheduled change: {:?}", e);
+            MAX_SLEEP
+        }
+    }
+}
+
+/// The event announcing that the scheduler changed the visibility of `post`.
+fn schedule_event(post: BlogPost) -> ContentEvent {
+    if post.visibility.is_listed() {
+        ContentEvent::PostPublished { id: post.id }
+    } else {
+        ContentEvent::PostUnpublished { id: post.id }
+    }
+}
+
+/// How long to sleep at `now` before the change due at `next`. Changes already due
+/// are picked up right away.
+fn sleep_until(next: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Duration {
+    next.map_or(MAX_SLEEP, |next| {
+        (next - now).to_std().unwrap_or_default().min(MAX_SLEEP)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::blog::Visibility;
+    use chrono::{Duration as TimeDelta, TimeZone};
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0)
+            .single()
+            .unwrap_or_default()
+    }
+
+    fn scheduled_draft() -> BlogPost {
+        let mut post = BlogPost::new(
+            "Release notes".to_string(),
+            "Content".to_string(),
+            "Mike".to_string(),
+            Vec::new(),
+            None,
+        );
+        post.publish_at = Some(now());
+        post.unpublish_at = Some(now() + TimeDelta::days(30));
+        post
+    }
+
+    #[test]
+    fn sleeps_until_the_next_change_but_not_longer_than_the_maximum() {
+        assert_eq!(
+            sleep_until(Some(now() + TimeDelta::seconds(5)), now()),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            sleep_until(Some(now() + TimeDelta::milliseconds(300)), now()),
+            Duration::from_millis(300)
+        );
+        assert_eq!(
+            sleep_until(Some(now() - TimeDelta::seconds(5)), now()),
+            Duration::ZERO
+        );
+        assert_eq!(
+            sleep_until(Some(now() + TimeDelta::days(1)), now()),
+            MAX_SLEEP
+        );
+        assert_eq!(sleep_until(None, now()), MAX_SLEEP);
+    }
+
+    #[test]
+    fn announces_each_schedule_transition() {
+        let mut post = scheduled_draft();
+        let id = post.id.clone();
+
+        // Not due yet
+        assert!(!post.apply_schedule(now() - TimeDelta::seconds(1)));
+
+        assert!(post.apply_schedule(now()));
+        assert_eq!(post.visibility, Visibility::Public);
+        assert_eq!(
+            schedule_event(post.clone()),
+            ContentEvent::PostPublished { id: id.clone() }
+        );
+
+        let expiry = now() + TimeDelta::days(30);
+        assert!(post.apply_schedule(expiry));
+        assert_eq!(post.visibility, Visibility::Archived);
+        assert_eq!(
+            schedule_event(post.clone()),
+            ContentEvent::PostUnpublished { id }
+        );
+        assert!(!post.apply_schedule(expiry + TimeDelta::days(1)));
+    }
+
+    #[test]
+    fn schedule_times_survive_storage_in_whole_seconds() {
+        let mut post = scheduled_draft();
+        post.publish_at = Some(now() + TimeDelta::milliseconds(750));
+
+        let stored = serde_json::to_value(&post).unwrap_or_default();
+        assert_eq!(stored["publish_at"], "2024-05-01T12:00:00Z");
+        assert_eq!(stored["unpublish_at"], "2024-05-31T12:00:00Z");
+
+        let loaded: Option<BlogPost> = serde_json::from_value(stored).ok();
+        assert_eq!(loaded.and_then(|post| post.publish_at), Some(now()));
+    }
+}
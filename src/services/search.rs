@@ -0,0 +1,500 @@
+//! Full-text search over published posts and books.
+//!
+//! Post titles, tags and content (HTML stripped), and book titles, authors, tags,
+//! descriptions and reviews go into an in-process inverted index. A match in a title
+//! weighs most, then tags and authors, then body text, each scaled by how rare the term
+//! is. The index is rebuilt whenever a post, series or book changes, and at the
+//! latest after [`INDEX_TTL`] so edits made directly in storage show up too.
+//!
+//! Each rebuild also produces the compact [`ClientSearchIndex`] served at
+//! `/search-index.json` for instant search in the browser.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+use leptos::logging;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::{
+    models::{
+        blog::BlogPost,
+        books::Book,
+        search::{
+            ClientSearchEntry, ClientSearchIndex, SearchHit, SearchKind, SnippetPart,
+            CLIENT_INDEX_VERSION,
+        },
+    },
+    rendering::render_markdown,
+    services::{
+        cosmos::{
+            blog_repository::{get_blog_service, BlogService},
+            book_repository::{get_book_service, BookService},
+        },
+        events,
+    },
+    utils::{
+        date_formatter::format_date_readable,
+        html::strip_tags,
+        text::{is_term, terms},
+    },
+};
+
+/// At most this many results are returned.
+pub const MAX_RESULTS: usize = 20;
+
+const TITLE_WEIGHT: f64 = 4.0;
+const TAG_WEIGHT: f64 = 3.0;
+const AUTHOR_WEIGHT: f64 = 2.0;
+const BODY_WEIGHT: f64 = 1.0;
+
+const INDEX_TTL: Duration = Duration::from_mins(15);
+
+/// Text terms kept per entry of the client index, which only needs enough of them to
+/// find what a post is about.
+const MAX_CLIENT_WORDS: usize = 150;
+
+/// Words of context kept before the first match of a snippet.
+const SNIPPET_LEAD_WORDS: usize = 8;
+const SNIPPET_CHARS: usize = 200;
+
+struct Document {
+    kind: SearchKind,
+    title: String,
+    path: String,
+    detail: String,
+    /// Plain text the snippet is taken from.
+    text: String,
+}
+
+/// Inverted index from terms to the posts and books containing them.
+#[derive(Default)]
+pub struct SearchIndex {
+    documents: Vec<Document>,
+    /// Documents containing each term, with its occurrences weighted by field.
+    postings: HashMap<String, Vec<(usize, f64)>>,
+    client_entries: Vec<ClientSearchEntry>,
+}
+
+impl SearchIndex {
+    /// Indexes `posts`, expected newest first, and `books`. Equally ranked results keep
+    /// this order.
+    #[must_use]
+    pub fn build(posts: &[BlogPost], books: &[Book]) -> Self {
+        let mut index = Self::default();
+
+        for post in posts {
+            let text = strip_tags(&render_markdown(&post.content).html);
+            let tags = post.tags.join(" ");
+            let fields = [
+                (post.title.as_str(), TITLE_WEIGHT),
+                (tags.as_str(), TAG_WEIGHT),
+                (text.as_str(), BODY_WEIGHT),
+            ];
+            let client_tags = terms(&tags);
+            let weights = term_weights(&fields);
+            index.add(
+                Document {
+                    kind: SearchKind::Post,
+                    title: post.title.clone(),
+                    path: post.path(),
+                    detail: format_date_readable(post.created_at),
+                    text,
+                },
+                weights,
+                client_tags,
+            );
+        }
+
+        for book in books {
+            let review = book.personal_review.clone().unwrap_or_default();
+            let text = format!("{} {review}", book.description);
+            let tags = book.tags.join(" ");
+            let takeaways = book.key_takeaways.join(" ");
+            let fields = [
+                (book.title.as_str(), TITLE_WEIGHT),
+                (book.author.as_str(), AUTHOR_WEIGHT),
+                (tags.as_str(), TAG_WEIGHT),
+                (text.as_str(), BODY_WEIGHT),
+                (takeaways.as_str(), BODY_WEIGHT),
+            ];
+            let client_tags = terms(&format!("{} {tags}", book.author));
+            let weights = term_weights(&fields);
+            index.add(
+                Document {
+                    kind: SearchKind::Book,
+                    title: book.title.clone(),
+                    path: book.path(),
+                    detail: format!("by {}", book.author),
+                    text: format!("{text} {takeaways}"),
+                },
+                weights,
+                client_tags,
+            );
+        }
+
+        index
+    }
+
+    fn add(&mut self, document: Document, weights: HashMap<String, f64>, mut tags: Vec<String>) {
+        tags.sort_unstable();
+        tags.dedup();
+        let id = self.documents.len();
+        for (term, weight) in weights {
+            self.postings.entry(term).or_default().push((id, weight));
+        }
+        self.client_entries.push(ClientSearchEntry {
+            kind: document.kind,
+            title: document.title.clone(),
+            path: document.path.clone(),
+            detail: document.detail.clone(),
+            words: frequent_terms(&document, &tags),
+            tags,
+        });
+        self.documents.push(document);
+    }
+
+    /// The compact index for searching in the browser, with entries in the same order.
+    #[must_use]
+    pub fn client_index(&self) -> ClientSearchIndex {
+        ClientSearchIndex {
+            version: CLIENT_INDEX_VERSION,
+            entries: self.client_entries.clone(),
+        }
+    }
+
+    /// Documents containing every term of `query`, best match first.
+    #[must_use]
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchHit> {
+        let query_terms: HashSet<String> = terms(query).into_iter().collect();
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let document_count = count_as_f64(self.documents.len());
+        let mut scores: HashMap<usize, (f64, usize)> = HashMap::new();
+        for term in &query_terms {
+            let Some(postings) = self.postings.get(term) else {
+                // Every term has to match
+                return Vec::new();
+            };
+            let idf = (1.0 + document_count / count_as_f64(postings.len())).ln();
+            for (id, weight) in postings {
+                let (score, matched_terms) = scores.entry(*id).or_default();
+                *score += weight * idf;
+                *matched_terms += 1;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores
+            .into_iter()
+            .filter(|(_, (_, matched_terms))| *matched_terms == query_terms.len())
+            .map(|(id, (score, _))| (id, score))
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(id, score)| {
+                let document = &self.documents[id];
+                SearchHit {
+                    kind: document.kind,
+                    title: document.title.clone(),
+                    path: document.path.clone(),
+                    detail: document.detail.clone(),
+                    snippet: snippet(&document.text, &query_terms),
+                    score,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone)]
+struct CachedIndex {
+    built_at: Instant,
+    generated_at: DateTime<Utc>,
+    index: Arc<SearchIndex>,
+    /// [`SearchIndex::client_index`] serialized once per rebuild.
+    client_json: Arc<String>,
+}
+
+static INDEX: LazyLock<RwLock<Option<CachedIndex>>> = LazyLock::new(|| RwLock::new(None));
+
+/// Published posts and books matching `query`, best match first.
+///
+/// # Errors
+///
+/// Returns an error if the index has to be rebuilt and the content cannot be loaded.
+pub async fn search(
+    blogs: &BlogService,
+    books: &BookService,
+    query: &str,
+) -> Result<Vec<SearchHit>> {
+    let cached = cached_or_rebuilt(blogs, books).await?;
+    Ok(cached.index.search(query, MAX_RESULTS))
+}
+
+/// The [`ClientSearchIndex`] as JSON, and when it was generated.
+///
+/// # Errors
+///
+/// Returns an error if the index has to be rebuilt and the content cannot be loaded.
+pub async fn client_index_json(
+    blogs: &BlogService,
+    books: &BookService,
+) -> Result<(Arc<String>, DateTime<Utc>)> {
+    let cached = cached_or_rebuilt(blogs, books).await?;
+    Ok((cached.client_json, cached.generated_at))
+}
+
+async fn cached_or_rebuilt(blogs: &BlogService, books: &BookService) -> Result<CachedIndex> {
+    match current_index() {
+        Some(cached) => Ok(cached),
+        None => rebuild_index(blogs, books).await,
+    }
+}
+
+fn current_index() -> Option<CachedIndex> {
+    let cached = INDEX.read().ok()?;
+    cached
+        .as_ref()
+        .filter(|cached| cached.built_at.elapsed() < INDEX_TTL)
+        .cloned()
+}
+
+async fn rebuild_index(blogs: &BlogService, books: &BookService) -> Result<CachedIndex> {
+    let (posts, books) =
+        futures::try_join!(blogs.list_published_blog_posts(), books.get_all_books())?;
+    let index = SearchIndex::build(&posts, &books);
+    let client_json = serde_json::to_string(&index.client_index())
+        .wrap_err("Failed to serialize the client search index")?;
+
+    let cached = CachedIndex {
+        built_at: Instant::now(),
+        generated_at: Utc::now(),
+        index: Arc::new(index),
+        client_json: Arc::new(client_json),
+    };
+    if let Ok(mut slot) = INDEX.write() {
+        *slot = Some(cached.clone());
+    }
+    Ok(cached)
+}
+
+/// Builds the index right away and rebuilds it on every content change, so searches
+/// never wait for it, find hidden posts or return stale text.
+pub fn spawn_search_indexer() {
+    let mut receiver = events::subscribe();
+    tokio::spawn(async move {
+        refresh_index().await;
+        while let Ok(_) | Err(RecvError::Lagged(_)) = receiver.recv().await {
+            refresh_index().await;
+        }
+    });
+}
+
+async fn refresh_index() {
+    let result = async {
+        let blogs = get_blog_service().map_err(|e| eyre!("Failed to get Cosmos service: {e}"))?;
+        rebuild_index(blogs, get_book_service()?).await
+    }
+    .await;
+
+    if let Err(e) = result {
+        logging::error!("Failed to rebuild the search index: {:?}", e);
+        // Searches rebuild it on demand rather than using outdated results
+        if let Ok(mut cached) = INDEX.write() {
+            *cached = None;
+        }
+    }
+}
+
+/// Terms of `fields` with their occurrences weighted by field.
+fn term_weights(fields: &[(&str, f64)]) -> HashMap<String, f64> {
+    let mut weights: HashMap<String, f64> = HashMap::new();
+    for (text, weight) in fields {
+        for term in terms(text) {
+            *weights.entry(term).or_default() += weight;
+        }
+    }
+    weights
+}
+
+/// The `MAX_CLIENT_WORDS` most frequent terms of the text of `document` that are
+/// neither in its title nor in `tags`, most frequent first.
+fn frequent_terms(document: &Document, tags: &[String]) -> Vec<String> {
+    let known: HashSet<String> = terms(&document.title)
+        .into_iter()
+        .chain(tags.iter().cloned())
+        .collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for term in terms(&document.text) {
+        if !known.contains(&term) {
+            *counts.entry(term).or_default() += 1;
+        }
+    }
+
+    let mut words: Vec<(String, usize)> = counts.into_iter().collect();
+    words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    words
+        .into_iter()
+        .take(MAX_CLIENT_WORDS)
+        .map(|(word, _)| word)
+        .collect()
+}
+
+/// Excerpt of `text` starting a few words before the first word in `query_terms`, with
+/// every matching word highlighted.
+fn snippet(text: &str, query_terms: &HashSet<String>) -> Vec<SnippetPart> {
+    let words = word_spans(text);
+    let is_match = |&(start, end): &(usize, usize)| {
+        let word = text[start..end].to_lowercase();
+        is_term(&word) && query_terms.contains(&word)
+    };
+
+    let first_match = words.iter().position(is_match).unwrap_or(0);
+    let from = first_match.saturating_sub(SNIPPET_LEAD_WORDS);
+    let Some(&(start, first_end)) = words.get(from) else {
+        return Vec::new();
+    };
+    let mut end = first_end;
+    for &(_, word_end) in &words[from..] {
+        if text[start..word_end].chars().count() > SNIPPET_CHARS {
+            break;
+        }
+        end = word_end;
+    }
+
+    let mut parts = Vec::new();
+    if from > 0 {
+        push_part(&mut parts, "… ", false);
+    }
+    let mut cursor = start;
+    for span in words[from..]
+        .iter()
+        .take_while(|(_, word_end)| *word_end <= end)
+    {
+        if is_match(span) {
+            push_part(&mut parts, &text[cursor..span.0], false);
+            push_part(&mut parts, &text[span.0..span.1], true);
+            cursor = span.1;
+        }
+    }
+    push_part(&mut parts, &text[cursor..end], false);
+    if words.last().is_some_and(|&(_, last_end)| last_end > end) {
+        push_part(&mut parts, " …", false);
+    }
+    parts
+}
+
+/// Appends `text` to `parts`, merging it into the previous part when neither is
+/// highlighted.
+fn push_part(parts: &mut Vec<SnippetPart>, text: &str, highlighted: bool) {
+    if text.is_empty() {
+        return;
+    }
+    match parts.last_mut() {
+        Some(last) if !highlighted && !last.highlighted => last.text.push_str(text),
+        _ => parts.push(SnippetPart {
+            text: text.to_string(),
+            highlighted,
+        }),
+    }
+}
+
+/// Byte ranges of the alphanumeric words of `text`, the same words [`terms`] sees.
+fn word_spans(text: &str) -> Vec<(usize, usize)> {
+    let mut spans = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices() {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                spans.push((word_start, index));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(word_start) = start {
+        spans.push((word_start, text.len()));
+    }
+    spans
+}
+
+/// Document and term counts stay far below 2^52, so the conversion is exact.
+#[allow(clippy::cast_precision_loss)]
+fn count_as_f64(count: usize) -> f64 {
+    count as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(title: &str, content: &str, tags: &[&str]) -> BlogPost {
+        BlogPost::new(
+            title.to_string(),
+            content.to_string(),
+            "Mike".to_string(),
+            tags.iter().map(ToString::to_string).collect(),
+            None,
+        )
+    }
+
+    #[test]
+    fn ranks_title_matches_above_body_matches() {
+        let posts = [
+            post("Notes on testing", "Some words about async Rust.", &[]),
+            post("Async Rust in practice", "Futures and executors.", &[]),
+        ];
+        let index = SearchIndex::build(&posts, &[]);
+
+        let hits = index.search("async rust", MAX_RESULTS);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].title, "Async Rust in practice");
+    }
+
+    #[test]
+    fn requires_every_term_to_match() {
+        let posts = [post("Leptos routing", "Routes and params.", &["rust"])];
+        let index = SearchIndex::build(&posts, &[]);
+
+        assert_eq!(index.search("leptos rust", MAX_RESULTS).len(), 1);
+        assert!(index.search("leptos python", MAX_RESULTS).is_empty());
+        assert!(index.search("the", MAX_RESULTS).is_empty());
+    }
+
+    #[test]
+    fn highlights_matches_in_the_snippet() {
+        let query_terms = HashSet::from(["ownership".to_string()]);
+
+        let parts = snippet("Rust enforces ownership at compile time.", &query_terms);
+
+        assert_eq!(
+            parts,
+            vec![
+                SnippetPart {
+                    text: "Rust enforces ".to_string(),
+                    highlighted: false,
+                },
+                SnippetPart {
+                    text: "ownership".to_string(),
+                    highlighted: true,
+                },
+                SnippetPart {
+                    text: " at compile time".to_string(),
+                    highlighted: false,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt;
+
+/// Placeholder emitted wherever a secret would otherwise be printed or serialized.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// A credential that must never end up in logs, panic messages or serialized output.
+///
+/// `Debug`, `Display` and `Serialize` all emit [`REDACTED`]; the real value is only
+/// reachable through [`SecretString::expose_secret`].
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct SecretString(String);
+
+impl SecretString {
+    #[must_use]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the secret value. Only call this where the credential is handed to a client.
+    #[must_use]
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretString({REDACTED})")
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUE: &str = "c2VjcmV0LWNvc21vcy1rZXk=";
+
+    #[test]
+    fn redacts_debug_display_and_serialize() {
+        let secret = SecretString::new(VALUE);
+
+        assert!(!format!("{secret:?}").contains(VALUE));
+        assert!(!format!("{secret:#?}").contains(VALUE));
+        assert!(!secret.to_string().contains(VALUE));
+        assert_eq!(
+            serde_json::to_string(&secret).ok().as_deref(),
+            Some("\"[REDACTED]\"")
+        );
+    }
+
+    #[test]
+    fn deserializes_and_exposes_the_real_value() {
+        let secret: Result<SecretString, _> = serde_json::from_str(&format!("\"{VALUE}\""));
+        assert_eq!(secret.ok().as_ref().map(SecretString::expose_secret), Some(VALUE));
+    }
+}
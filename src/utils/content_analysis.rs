@@ -0,0 +1,467 @@
+//! Statistics derived from rendered post HTML: word and code counts, reading time,
+//! an excerpt for post cards and a meta description.
+//!
+//! The HTML comes from our own Markdown pipeline, so a small tokenizer that
+//! understands tags, comments and entities is enough; no DOM is built.
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::text::truncate_at_word;
+
+/// Average reading speed for prose.
+const WORDS_PER_MINUTE: usize = 225;
+/// Code is read line by line and much slower than prose.
+const CODE_LINES_PER_MINUTE: usize = 25;
+
+/// Maximum length of [`ContentStats::excerpt`] in characters.
+pub const EXCERPT_LENGTH: usize = 200;
+/// Maximum length of [`ContentStats::meta_description`] in characters.
+pub const META_DESCRIPTION_LENGTH: usize = 160;
+
+/// Elements that never contain text.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Elements whose text is markup chrome or not prose (formulas, icons, buttons).
+const SKIPPED_ELEMENTS: &[&str] = &["button", "math", "script", "style", "svg", "template"];
+
+/// Classes of generated elements whose text is not part of the post.
+const SKIPPED_CLASSES: &[&str] = &["footnote-ref", "heading-anchor", "language-label"];
+
+/// Elements whose text is counted but left out of excerpts.
+const ASIDE_ELEMENTS: &[&str] = &["aside", "figcaption", "section", "table"];
+
+/// Inline elements do not separate words; every other tag does.
+const INLINE_ELEMENTS: &[&str] = &[
+    "a", "abbr", "b", "code", "del", "em", "i", "kbd", "mark", "s", "small", "span", "strong",
+    "sub", "sup", "u",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentStats {
+    /// Words of prose, excluding code blocks.
+    pub word_count: usize,
+    pub code_block_count: usize,
+    /// Non-blank lines across all code blocks.
+    pub code_line_count: usize,
+    /// Estimated reading time in whole minutes, at least one.
+    pub reading_time_minutes: u32,
+    /// Plain text opening of the post, at most [`EXCERPT_LENGTH`] characters.
+    pub excerpt: String,
+    /// Plain text summary, at most [`META_DESCRIPTION_LENGTH`] characters.
+    pub meta_description: String,
+}
+
+/// Analyzes rendered post HTML.
+#[must_use]
+pub fn analyze_html(html: &str) -> ContentStats {
+    let mut open: Vec<Element> = Vec::new();
+    let mut prose = String::new();
+    let mut paragraphs = String::new();
+    let mut code = String::new();
+    let mut word_count = 0;
+    let mut code_block_count = 0;
+    let mut code_line_count = 0;
+
+    for token in Tokenizer::new(html) {
+        match token {
+            Token::Start { name, class } => {
+                if !INLINE_ELEMENTS.contains(&name.as_str()) {
+                    prose.push(' ');
+                    paragraphs.push(' ');
+                }
+                if VOID_ELEMENTS.contains(&name.as_str()) {
+                    continue;
+                }
+                let kind = ElementKind::of(&name, class.as_deref());
+                if kind == ElementKind::Code && !open.iter().any(Element::is_code) {
+                    code.clear();
+                }
+                open.push(Element { name, kind });
+            }
+            Token::End { name } => {
+                if !INLINE_ELEMENTS.contains(&name.as_str()) {
+                    prose.push(' ');
+                    paragraphs.push(' ');
+                }
+                // Pop up to the matching element so stray end tags cannot unbalance the stack
+                let Some(position) = open.iter().rposition(|element| element.name == name) else {
+                    continue;
+                };
+                let closed = open.split_off(position);
+                if closed.iter().any(Element::is_code) && !open.iter().any(Element::is_code) {
+                    code_block_count += 1;
+                    code_line_count += code.lines().filter(|line| !line.trim().is_empty()).count();
+                }
+            }
+            Token::Text(text) => {
+                if open
+                    .iter()
+                    .any(|element| element.kind == ElementKind::Skipped)
+                {
+                    continue;
+                }
+                let text = html_escape::decode_html_entities(text);
+                if open.iter().any(Element::is_code) {
+                    code.push_str(&text);
+                    continue;
+                }
+                word_count += count_words(&text);
+                prose.push_str(&text);
+                let in_paragraph = open.iter().any(|element| element.name == "p");
+                let in_aside = open
+                    .iter()
+                    .any(|element| element.kind == ElementKind::Aside);
+                if in_paragraph && !in_aside {
+                    paragraphs.push_str(&text);
+                }
+            }
+        }
+    }
+
+    // Posts without plain paragraphs (only lists or headings) still get a summary
+    let summary = if has_words(&paragraphs) {
+        paragraphs
+    } else {
+        prose
+    };
+    let summary = collapse_whitespace(&summary);
+
+    ContentStats {
+        word_count,
+        code_block_count,
+        code_line_count,
+        reading_time_minutes: reading_time_minutes(word_count, code_line_count),
+        excerpt: truncate_at_word(&summary, EXCERPT_LENGTH),
+        meta_description: truncate_at_word(&summary, META_DESCRIPTION_LENGTH),
+    }
+}
+
+fn reading_time_minutes(word_count: usize, code_line_count: usize) -> u32 {
+    let seconds = (word_count * 60).div_ceil(WORDS_PER_MINUTE)
+        + (code_line_count * 60).div_ceil(CODE_LINES_PER_MINUTE);
+    u32::try_from(seconds.div_ceil(60).max(1)).unwrap_or(u32::MAX)
+}
+
+/// A word is a whitespace separated run containing at least one letter or digit,
+/// so dashes, bullets and emoji on their own are not counted.
+fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+fn has_words(text: &str) -> bool {
+    count_words(text) > 0
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ElementKind {
+    Normal,
+    Code,
+    Skipped,
+    Aside,
+}
+
+impl ElementKind {
+    fn of(name: &str, class: Option<&str>) -> Self {
+        let has_skipped_class = class.is_some_and(|class| {
+            class
+                .split_whitespace()
+                .any(|class| SKIPPED_CLASSES.contains(&class))
+        });
+
+        if SKIPPED_ELEMENTS.contains(&name) || has_skipped_class {
+            Self::Skipped
+        } else if name == "pre" {
+            Self::Code
+        } else if ASIDE_ELEMENTS.contains(&name) {
+            Self::Aside
+        } else {
+            Self::Normal
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Element {
+    name: String,
+    kind: ElementKind,
+}
+
+impl Element {
+    fn is_code(&self) -> bool {
+        self.kind == ElementKind::Code
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    /// Opening tag; self-closing tags produce only this token.
+    Start {
+        name: String,
+        class: Option<String>,
+    },
+    End {
+        name: String,
+    },
+    /// Text between tags, with entities still encoded.
+    Text(&'a str),
+}
+
+/// Splits HTML into tags and text. Comments, doctypes and processing instructions
+/// are dropped, and the contents of `script` and `style` are consumed as one block.
+struct Tokenizer<'a> {
+    rest: &'a str,
+    /// Set after `<script>` or `<style>`: everything up to its end tag is raw text.
+    raw_text_end: Option<String>,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(html: &'a str) -> Self {
+        Self {
+            rest: html,
+            raw_text_end: None,
+        }
+    }
+
+    fn take(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.rest.is_empty() {
+                return None;
+            }
+
+            if let Some(end_tag) = self.raw_text_end.take() {
+                let end =
+                    find_ascii_case_insensitive(self.rest, &end_tag).unwrap_or(self.rest.len());
+                let text = self.take(end);
+                if !text.is_empty() {
+                    return Some(Token::Text(text));
+                }
+                continue;
+            }
+
+            if !self.rest.starts_with('<') {
+                let end = self.rest.find('<').unwrap_or(self.rest.len());
+                return Some(Token::Text(self.take(end)));
+            }
+
+            if self.rest.starts_with("<!--") {
+                let end = self.rest.find("-->").map_or(self.rest.len(), |end| end + 3);
+                self.take(end);
+                continue;
+            }
+
+            if self.rest.starts_with("<!") || self.rest.starts_with("<?") {
+                let end = self.rest.find('>').map_or(self.rest.len(), |end| end + 1);
+                self.take(end);
+                continue;
+            }
+
+            let is_end_tag = self.rest.starts_with("</");
+            let name_start = if is_end_tag { 2 } else { 1 };
+            let name_len = self.rest[name_start..]
+                .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+                .unwrap_or(self.rest.len() - name_start);
+
+            // A `<` that does not start a tag, e.g. "a < b", is plain text
+            if name_len == 0
+                || !self.rest[name_start..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                let end = self.rest[1..]
+                    .find('<')
+                    .map_or(self.rest.len(), |end| end + 1);
+                return Some(Token::Text(self.take(end)));
+            }
+
+            let name = self.rest[name_start..name_start + name_len].to_ascii_lowercase();
+            let tag_len = tag_end(&self.rest[name_start + name_len..])
+                .map_or(self.rest.len(), |end| name_start + name_len + end);
+            let tag = self.take(tag_len);
+
+            if is_end_tag {
+                return Some(Token::End { name });
+            }
+
+            if matches!(name.as_str(), "script" | "style") && !tag.ends_with("/>") {
+                self.raw_text_end = Some(format!("</{name}"));
+            }
+            let class = attribute(&tag[name_start + name_len..], "class");
+            return Some(Token::Start { name, class });
+        }
+    }
+}
+
+/// Byte offset just past the `>` closing a tag, skipping over quoted attribute values.
+fn tag_end(attributes: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in attributes.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Value of the attribute `name` in the attribute part of a start tag.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+    while !rest.is_empty() && !rest.starts_with('>') {
+        let key_len = rest
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '=' | '>' | '/'))
+            .unwrap_or(rest.len());
+        let key = &rest[..key_len];
+        rest = rest[key_len..].trim_start();
+
+        let mut value = None;
+        if let Some(after_equals) = rest.strip_prefix('=') {
+            let after_equals = after_equals.trim_start();
+            let quote = after_equals
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\''));
+            let (raw, remaining) = if let Some(quote) = quote {
+                let inner = &after_equals[1..];
+                let end = inner.find(quote).unwrap_or(inner.len());
+                (&inner[..end], inner.get(end + 1..).unwrap_or_default())
+            } else {
+                let end = after_equals
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(after_equals.len());
+                after_equals.split_at(end)
+            };
+            value = Some(raw);
+            rest = remaining;
+        }
+
+        if key.eq_ignore_ascii_case(name) {
+            return Some(html_escape::decode_html_entities(value.unwrap_or_default()).into_owned());
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if key_len == 0 && value.is_none() {
+            // Not an attribute we understand; stop instead of looping forever
+            break;
+        }
+    }
+    None
+}
+
+fn find_ascii_case_insensitive(haystack: &str, needle: &str) -> Option<usize> {
+    haystack
+        .as_bytes()
+        .windows(needle.len())
+        .position(|window| window.eq_ignore_ascii_case(needle.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_words_in_non_ascii_prose() {
+        let stats = analyze_html("<p>Hyvää päivää, Müller! Ärger über Straße – naïve café.</p>");
+
+        assert_eq!(stats.word_count, 8);
+        assert_eq!(stats.code_block_count, 0);
+        assert_eq!(stats.reading_time_minutes, 1);
+    }
+
+    #[test]
+    fn ignores_markup_and_decodes_entities() {
+        let stats = analyze_html(
+            r##"<h2 id="intro">Intro<a class="heading-anchor" href="#intro">#</a></h2><p>Fish &amp; chips<sup class="footnote-ref"><a href="#fn-1">1</a></sup> are <em>great</em>.</p>"##,
+        );
+
+        assert_eq!(stats.word_count, 5);
+        assert_eq!(stats.excerpt, "Fish & chips are great.");
+    }
+
+    #[test]
+    fn counts_code_blocks_separately_from_prose() {
+        let html = concat!(
+            "<p>Short intro.</p>",
+            r#"<div class="code-block-wrapper"><div class="code-block-header"><span class="language-label">Rust</span><button title="Copy">Copy</button></div>"#,
+            r#"<pre data-language="rust"><code class="language-rust"><span class="hl-keyword">fn</span> main() {"#,
+            "\n    println!(\"hello\");\n\n}\n</code></pre></div>",
+            "<p>Use <code>cargo run</code> to start it.</p>",
+        );
+        let stats = analyze_html(html);
+
+        assert_eq!(stats.word_count, 8);
+        assert_eq!(stats.code_block_count, 1);
+        assert_eq!(stats.code_line_count, 3);
+        assert_eq!(stats.excerpt, "Short intro. Use cargo run to start it.");
+    }
+
+    #[test]
+    fn code_slows_down_reading_time() {
+        let prose = format!("<p>{}</p>", "word ".repeat(200));
+        let code = format!("<pre><code>{}</code></pre>", "let x = 1;\n".repeat(30));
+
+        assert_eq!(analyze_html(&prose).reading_time_minutes, 1);
+        assert_eq!(analyze_html(&(prose + &code)).reading_time_minutes, 3);
+    }
+
+    #[test]
+    fn truncates_multi_byte_text_on_word_boundaries() {
+        let sentence = "Käännös äöå 日本語のテキスト ";
+        let html = format!("<p>{}</p>", sentence.repeat(20));
+        let stats = analyze_html(&html);
+
+        assert!(stats.excerpt.chars().count() <= EXCERPT_LENGTH);
+        assert!(stats.meta_description.chars().count() <= META_DESCRIPTION_LENGTH);
+        assert!(stats.meta_description.ends_with("..."));
+        let kept = stats.meta_description.trim_end_matches("...");
+        assert!(sentence.repeat(20).starts_with(kept));
+        assert!(kept.ends_with(|c: char| !c.is_whitespace()));
+    }
+
+    #[test]
+    fn prefers_paragraphs_over_headings_and_callouts() {
+        let stats = analyze_html(concat!(
+            "<h1>Title</h1>",
+            r#"<aside class="callout callout-note"><p class="callout-title">Note</p><p>Draft.</p></aside>"#,
+            "<p>First paragraph.</p><ul><li>Item</li></ul>",
+        ));
+
+        assert_eq!(stats.excerpt, "First paragraph.");
+        assert_eq!(stats.word_count, 6);
+    }
+
+    #[test]
+    fn falls_back_to_all_text_without_paragraphs() {
+        let stats = analyze_html("<ul><li>Ein</li><li>Zwei</li></ul>");
+
+        assert_eq!(stats.excerpt, "Ein Zwei");
+        assert_eq!(stats.meta_description, "Ein Zwei");
+    }
+
+    #[test]
+    fn handles_empty_and_malformed_html() {
+        assert_eq!(analyze_html("").reading_time_minutes, 1);
+        assert_eq!(analyze_html("").excerpt, "");
+
+        let stats = analyze_html("<p>1 < 2 and <b>bold</i> text</p></div><!-- note -->");
+        assert_eq!(stats.word_count, 5);
+        assert_eq!(stats.excerpt, "1 < 2 and bold text");
+    }
+}
@@ -36,3 +36,9 @@ pub fn format_date_relative(date: DateTime<Utc>) -> String {
         "Just now".to_string()
     }
 }
+
+/// Formats the month of a UTC datetime (e.g. "March 2024")
+#[must_use]
+pub fn format_month_year(date: DateTime<Utc>) -> String {
+    date.format("%B %Y").to_string()
+}
@@ -1,6 +1,68 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SecondsFormat, Utc};
 
 #[must_use]
 pub fn format_date(date: &DateTime<Utc>) -> String {
     date.format("%B %d, %Y").to_string()
 }
+
+/// `date` as stored for storage queries that compare timestamps. Cosmos DB compares
+/// the RFC 3339 strings, which only sort chronologically at a fixed precision
+/// ("…:00Z" sorts after "…:00.5Z"), so these are always written in whole seconds.
+#[must_use]
+pub fn storage_timestamp(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Serde helpers for optional timestamps that storage queries compare, written with
+/// [`storage_timestamp`]. Use with `#[serde(default, with = "...")]`.
+pub mod storage_time {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// # Errors
+    ///
+    /// Returns the serializer's error.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        date: &Option<DateTime<Utc>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match date {
+            Some(date) => serializer.serialize_some(&super::storage_timestamp(*date)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the value is neither null nor an RFC 3339 timestamp.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<DateTime<Utc>>, D::Error> {
+        Option::<DateTime<Utc>>::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn storage_timestamps_sort_chronologically() {
+        let second = Utc
+            .with_ymd_and_hms(2024, 5, 1, 12, 0, 0)
+            .single()
+            .unwrap_or_default();
+        let later = second + Duration::milliseconds(500);
+
+        assert_eq!(storage_timestamp(second), "2024-05-01T12:00:00Z");
+        assert_eq!(storage_timestamp(later), "2024-05-01T12:00:00Z");
+        // Serde's format keeps the fraction, which sorts before the whole second
+        assert!(
+            later.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+                < second.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        );
+        assert!(storage_timestamp(second + Duration::seconds(1)) > storage_timestamp(later));
+    }
+}
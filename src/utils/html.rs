@@ -0,0 +1,133 @@
+/// `MathML` presentation elements produced by the math renderer.
+const MATHML_TAGS: &[&str] = &[
+    "math",
+    "semantics",
+    "annotation",
+    "mrow",
+    "mi",
+    "mn",
+    "mo",
+    "ms",
+    "mtext",
+    "mspace",
+    "mfrac",
+    "msqrt",
+    "mroot",
+    "msub",
+    "msup",
+    "msubsup",
+    "munder",
+    "mover",
+    "munderover",
+    "mmultiscripts",
+    "mprescripts",
+    "none",
+    "mtable",
+    "mtr",
+    "mtd",
+    "mstyle",
+    "mpadded",
+    "mphantom",
+    "menclose",
+    "merror",
+];
+
+/// Layout attributes of the `MathML` elements; none of them can carry script or URLs.
+const MATHML_ATTRIBUTES: &[&str] = &[
+    "xmlns",
+    "display",
+    "displaystyle",
+    "scriptlevel",
+    "mathvariant",
+    "stretchy",
+    "fence",
+    "separator",
+    "accent",
+    "accentunder",
+    "largeop",
+    "movablelimits",
+    "symmetric",
+    "form",
+    "lspace",
+    "rspace",
+    "minsize",
+    "maxsize",
+    "width",
+    "height",
+    "depth",
+    "linethickness",
+    "notation",
+    "columnalign",
+    "columnspacing",
+    "rowspacing",
+    "encoding",
+];
+
+/// Sanitizes HTML with ammonia while keeping the tags used by our code block markup,
+/// task list checkboxes, heading anchors, footnotes, callouts, `MathML` and table column
+/// alignment.
+///
+/// This runs in every build, so HTML rendered in the browser (the editor preview) is
+/// sanitized the same way as server-rendered pages.
+#[must_use]
+pub fn sanitize_html(html: &str) -> String {
+    use ammonia::Builder;
+
+    let mut builder = Builder::default();
+    builder
+        .add_tags(&["div", "span", "button", "svg", "path", "input", "section"])
+        .add_generic_attributes(&[
+            "class",
+            "stroke",
+            "fill",
+            "viewBox",
+            "stroke-linecap",
+            "stroke-linejoin",
+            "stroke-width",
+        ])
+        .add_tag_attributes("input", &["type", "checked", "disabled"])
+        .add_tag_attributes("pre", &["data-language"])
+        .add_tag_attributes("a", &["aria-label"])
+        .add_tag_attributes("aside", &["role"])
+        .add_tag_attributes("li", &["id"])
+        .add_tag_attributes("sup", &["id"])
+        .add_tag_attributes("h1", &["id"])
+        .add_tag_attributes("h2", &["id"])
+        .add_tag_attributes("h3", &["id"])
+        .add_tag_attributes("h4", &["id"])
+        .add_tag_attributes("h5", &["id"])
+        .add_tag_attributes("h6", &["id"])
+        .add_tag_attributes("th", &["style"])
+        .add_tag_attributes("td", &["style"])
+        .filter_style_properties(["text-align"].into())
+        .add_tags(MATHML_TAGS);
+    for tag in MATHML_TAGS {
+        builder.add_tag_attributes(tag, MATHML_ATTRIBUTES);
+    }
+
+    builder.clean(html).to_string()
+}
+
+/// Removes every tag and returns the text content with whitespace collapsed.
+#[cfg(feature = "ssr")]
+#[must_use]
+pub fn strip_tags(html: &str) -> String {
+    let text = ammonia::Builder::empty().clean(html).to_string();
+    let text = html_escape::decode_html_entities(&text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_inline_event_handlers() {
+        let html = sanitize_html(
+            r#"<p onclick="alert(1)">Hi</p><button class="copy-button" onclick="steal()">Copy</button>"#,
+        );
+
+        assert!(!html.contains("onclick"), "handler survived: {html}");
+        assert!(html.contains(r#"<button class="copy-button">"#));
+    }
+}
@@ -1,3 +1,7 @@
+pub mod content_analysis;
 pub mod date_formatter;
 pub mod datetime;
+pub mod html;
+pub mod slug;
 pub mod text;
+pub mod url;
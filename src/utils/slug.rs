@@ -0,0 +1,106 @@
+use deunicode::deunicode;
+
+/// Longest slug we generate, cut at a word boundary when possible.
+const MAX_SLUG_LENGTH: usize = 80;
+
+/// Fallback for titles that contain no transliterable characters at all.
+const EMPTY_SLUG: &str = "post";
+
+/// Turns a title into a URL slug, transliterating non-ASCII characters first
+/// so that e.g. "Hyvää päivää" becomes `hyvaa-paivaa`.
+#[must_use]
+pub fn slugify(input: &str) -> String {
+    let ascii = deunicode(input).to_lowercase();
+
+    let mut slug = String::with_capacity(ascii.len());
+    for c in ascii.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    if slug.len() > MAX_SLUG_LENGTH {
+        slug.truncate(MAX_SLUG_LENGTH);
+        if let Some(last_dash) = slug.rfind('-') {
+            slug.truncate(last_dash);
+        }
+    }
+
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        EMPTY_SLUG.to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Returns `base` if it is free, otherwise the first free `base-2`, `base-3`, ...
+/// Slugs in `reserved` are never free, for URL segments that other routes own.
+#[must_use]
+pub fn unique_slug(base: &str, reserved: &[&str], is_taken: impl Fn(&str) -> bool) -> String {
+    let is_free = |candidate: &str| !reserved.contains(&candidate) && !is_taken(candidate);
+    if is_free(base) {
+        return base.to_string();
+    }
+
+    (2..=usize::MAX)
+        .map(|n| format!("{base}-{n}"))
+        .find(|candidate| is_free(candidate))
+        .unwrap_or_else(|| base.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transliterates_non_ascii_titles() {
+        assert_eq!(slugify("Hyvää päivää"), "hyvaa-paivaa");
+        assert_eq!(slugify("Crème Brûlée"), "creme-brulee");
+        assert_eq!(slugify("Straße"), "strasse");
+    }
+
+    #[test]
+    fn collapses_separators_and_trims_dashes() {
+        assert_eq!(slugify("  Rust -- & __ Leptos!  "), "rust-leptos");
+        assert_eq!(slugify("C++ in 2024?"), "c-in-2024");
+    }
+
+    #[test]
+    fn falls_back_for_empty_and_symbol_only_titles() {
+        assert_eq!(slugify(""), EMPTY_SLUG);
+        assert_eq!(slugify("?!* --- ###"), EMPTY_SLUG);
+    }
+
+    #[test]
+    fn cuts_long_titles_at_a_word_boundary() {
+        let slug = slugify(&"word ".repeat(40));
+
+        assert!(slug.len() <= MAX_SLUG_LENGTH);
+        assert!(slug.ends_with("word"));
+    }
+
+    #[test]
+    fn numbers_taken_slugs_from_two() {
+        assert_eq!(unique_slug("rust", &[], |_| false), "rust");
+        assert_eq!(unique_slug("rust", &[], |slug| slug == "rust"), "rust-2");
+        assert_eq!(
+            unique_slug("rust", &[], |slug| ["rust", "rust-2"].contains(&slug)),
+            "rust-3"
+        );
+    }
+
+    #[test]
+    fn never_returns_reserved_slugs() {
+        let reserved = ["tags", "archive"];
+
+        assert_eq!(unique_slug("tags", &reserved, |_| false), "tags-2");
+        assert_eq!(
+            unique_slug("archive", &reserved, |slug| slug == "archive-2"),
+            "archive-3"
+        );
+        assert_eq!(unique_slug("tagsoup", &reserved, |_| false), "tagsoup");
+    }
+}
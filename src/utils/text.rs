@@ -1,3 +1,10 @@
+/// Words too common to say anything about the topic of a text.
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "an", "and", "are", "as", "at", "be", "by", "can", "for", "from", "how", "i",
+    "in", "into", "is", "it", "its", "my", "of", "on", "or", "our", "that", "the", "this", "to",
+    "we", "what", "when", "why", "with", "you", "your",
+];
+
 #[must_use]
 pub fn truncate_text(text: &str, max_length: usize) -> String {
     if text.len() <= max_length {
@@ -8,3 +15,72 @@ pub fn truncate_text(text: &str, max_length: usize) -> String {
         truncated
     }
 }
+
+/// Shortens `text` to at most `max_chars` characters, ellipsis included, cutting at the
+/// last word boundary. Counts characters rather than bytes, so it never splits one.
+#[must_use]
+pub fn truncate_at_word(text: &str, max_chars: usize) -> String {
+    const ELLIPSIS: &str = "...";
+
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let budget = max_chars.saturating_sub(ELLIPSIS.len());
+    let cut = text
+        .char_indices()
+        .nth(budget)
+        .map_or(text.len(), |(index, _)| index);
+    let mut truncated = &text[..cut];
+    // Drop the partial word unless the text is one long word
+    let next_is_boundary = text[cut..].starts_with(char::is_whitespace);
+    if !next_is_boundary {
+        if let Some(last_space) = truncated.rfind(char::is_whitespace) {
+            truncated = &truncated[..last_space];
+        }
+    }
+    let truncated = truncated.trim_end_matches(|c: char| c.is_whitespace() || ",;:".contains(c));
+
+    format!("{truncated}{ELLIPSIS}")
+}
+
+/// Lowercased words of `text` without stop words and single characters. Used to compare
+/// posts and to index them for search.
+#[must_use]
+pub fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|term| is_term(term))
+        .collect()
+}
+
+/// Whether the lowercased word `word` is kept by [`terms`].
+#[must_use]
+pub fn is_term(word: &str) -> bool {
+    word.chars().count() > 1 && !STOP_WORDS.contains(&word)
+}
+
+/// Number of single-character insertions, deletions, substitutions and swaps of
+/// neighbouring characters that turn `a` into `b`.
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // Rows for the previous two prefixes of `a`, enough for swaps
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
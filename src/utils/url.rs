@@ -0,0 +1,13 @@
+/// Turns a site-relative path into an absolute URL under `base_url`, the public
+/// address of the site without a trailing slash (see `SiteConfig::base_url`).
+/// URLs that already carry a scheme are returned unchanged.
+#[must_use]
+pub fn absolute_url(base_url: &str, path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string()
+    } else if path.starts_with('/') {
+        format!("{base_url}{path}")
+    } else {
+        format!("{base_url}/{path}")
+    }
+}